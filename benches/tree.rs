@@ -0,0 +1,274 @@
+//! Compares the lazy shrink tree against the eager `Vec`-of-children tree it replaced, in time and
+//! memory. `eager` below is the previous representation, vendored from the tree, `unfold` and
+//! `shrink::sequence_list` it had, so the baseline builds every node when a generator runs, as it
+//! used to. A counting global allocator records the peak number of live bytes and the number of
+//! allocations made while each side runs.
+//!
+//! The lazy side generates a value and walks the shrink path the runner takes when every shrink
+//! still fails, i.e. the first child at every level. The eager side builds the whole tree. Those
+//! trees are huge, the one for a single `u8` of 100 alone has about 10^22 nodes, so the eager side
+//! stops expanding after `NODE_BUDGET` nodes, and its figures are then only a lower bound. Small
+//! elements, e.g. in `0..=10`, keep the element trees complete so that the budget is spent on
+//! the list.
+//!
+//! Run with `cargo bench --bench tree`.
+
+extern crate hedgehog;
+
+use hedgehog::gen;
+use hedgehog::random;
+use hedgehog::range;
+use hedgehog::range::Size;
+use hedgehog::seed;
+use hedgehog::shrink;
+use hedgehog::tree;
+use hedgehog::tree::Tree;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// Stop expanding the eager tree after this many nodes.
+const NODE_BUDGET: usize = 1_000_000;
+
+// How often each case runs, the fastest time is reported.
+const RUNS: usize = 3;
+
+struct Counting;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let live = LIVE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+        PEAK.fetch_max(live, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// The tree as it was before children became lazy, with only what the generators below need.
+mod eager {
+    use super::NODE_BUDGET;
+    use hedgehog::shrink;
+    use std::rc::Rc;
+
+    #[derive(Clone)]
+    pub struct Tree<A> {
+        pub value: A,
+        pub children: Vec<Tree<A>>,
+    }
+
+    /// Counts the nodes built so far, so that the tree stops growing at `NODE_BUDGET`.
+    pub struct Budget {
+        pub nodes: usize,
+    }
+
+    impl Budget {
+        pub fn new() -> Self {
+            Budget { nodes: 0 }
+        }
+
+        pub fn exhausted(&self) -> bool {
+            self.nodes >= NODE_BUDGET
+        }
+
+        fn spend(&mut self) -> bool {
+            self.nodes += 1;
+            !self.exhausted()
+        }
+    }
+
+    pub fn unfold<A, F>(shrink: &F, x: A, budget: &mut Budget) -> Tree<A>
+    where
+        A: Clone,
+        F: Fn(A) -> Vec<A>,
+    {
+        let children = if budget.spend() {
+            shrink(x.clone())
+                .into_iter()
+                .map(|y| unfold(shrink, y, budget))
+                .collect()
+        } else {
+            vec![]
+        };
+        Tree { value: x, children }
+    }
+
+    // Drop the list removals first, then shrink each element, as `shrink::sequence_list` did.
+    pub fn sequence_list<A>(ts: Vec<Tree<A>>, budget: &mut Budget) -> Tree<Vec<A>>
+    where
+        A: Clone,
+    {
+        let value = ts.iter().map(|t| t.value.clone()).collect();
+        let children = if budget.spend() {
+            let mut merged = shrink::vec(ts.clone());
+            merged.extend(shrink::elems(Rc::new(|t: Tree<A>| t.children), ts));
+            merged
+                .into_iter()
+                .map(|ts| sequence_list(ts, budget))
+                .collect()
+        } else {
+            vec![]
+        };
+        Tree { value, children }
+    }
+
+    pub fn filter<A, F>(p: &F, t: Tree<A>) -> Tree<A>
+    where
+        A: Clone,
+        F: Fn(&A) -> bool,
+    {
+        let children = t
+            .children
+            .into_iter()
+            .filter(|c| p(&c.value))
+            .map(|c| filter(p, c))
+            .collect();
+        Tree {
+            value: t.value,
+            children,
+        }
+    }
+}
+
+struct Measured<A> {
+    result: A,
+    time: Duration,
+    peak_bytes: usize,
+    allocations: usize,
+}
+
+// Run `f` a few times, keeping the fastest time, and count the memory of the last run.
+fn measure<A, F>(f: F) -> Measured<A>
+where
+    F: Fn() -> A,
+{
+    let mut time = Duration::MAX;
+    for _ in 1..RUNS {
+        let start = Instant::now();
+        drop(f());
+        time = time.min(start.elapsed());
+    }
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f();
+    let time = time.min(start.elapsed());
+    Measured {
+        result,
+        time,
+        peak_bytes: PEAK.load(Ordering::Relaxed) - live,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    }
+}
+
+// Walk down the first child at each level and count the nodes that had to be computed.
+fn walk_first<'a, A>(t: Tree<'a, A>) -> usize
+where
+    A: Clone + 'a,
+{
+    let mut nodes = 1;
+    let mut t = t;
+    loop {
+        let xs = t.children();
+        nodes += xs.len();
+        match xs.into_iter().next() {
+            None => return nodes,
+            Some(x) => t = x,
+        }
+    }
+}
+
+fn report<A>(name: &str, lazy: Measured<usize>, eager: Measured<(eager::Tree<A>, eager::Budget)>)
+where
+    A: Debug,
+{
+    let (t, budget) = &eager.result;
+    let (nodes, note) = if budget.exhausted() {
+        (
+            format!(">{}", NODE_BUDGET),
+            "whole tree, cut off at the budget",
+        )
+    } else {
+        (budget.nodes.to_string(), "whole tree")
+    };
+    println!("{}: {:?}", name, t.value);
+    println!(
+        "  {:<6} {:>10} {:>14} {:>16} {:>14}",
+        "", "nodes", "time", "peak bytes", "allocations"
+    );
+    println!(
+        "  {:<6} {:>10} {:>14.3?} {:>16} {:>14}   generate + one shrink path",
+        "lazy", lazy.result, lazy.time, lazy.peak_bytes, lazy.allocations
+    );
+    println!(
+        "  {:<6} {:>10} {:>14.3?} {:>16} {:>14}   {}",
+        "eager", nodes, eager.time, eager.peak_bytes, eager.allocations, note
+    );
+}
+
+fn towards_zero(x: u8) -> Vec<u8> {
+    shrink::towards(0, x)
+}
+
+// `gen::vec` of exactly 50 `u8`s. The old `gen::vec` built the eager trees of the elements,
+// combined them with `shrink::sequence_list` and then filtered out lists below the lower bound.
+fn bench_vec(hi: u8) {
+    let g = gen::vec(range::constant(50, 50), gen::u8(range::constant(0, hi)));
+    let r = gen::to_random(g);
+    let lazy = measure(|| walk_first(random::run(seed::from(7), Size(30), r.clone())));
+    let xs = random::run(seed::from(7), Size(30), r).value();
+    let eager = measure(|| {
+        let mut budget = eager::Budget::new();
+        let ts = xs
+            .iter()
+            .map(|&x| eager::unfold(&towards_zero, x, &mut budget))
+            .collect();
+        let t = eager::sequence_list(ts, &mut budget);
+        (eager::filter(&|xs: &Vec<u8>| xs.len() >= 50, t), budget)
+    });
+    let name = format!("gen::vec of 50 u8 in 0..={}", hi);
+    report(&name, lazy, eager);
+}
+
+// `shrink::sequence_list` on its own, over the shrink trees of a few `u8`s.
+fn bench_sequence_list(xs: Vec<u8>) {
+    let lazy = measure(|| {
+        let ts = xs
+            .iter()
+            .map(|&x| tree::unfold(Rc::new(|x| x), Rc::new(towards_zero), x))
+            .collect();
+        walk_first(shrink::sequence_list(ts))
+    });
+    let eager = measure(|| {
+        let mut budget = eager::Budget::new();
+        let ts = xs
+            .iter()
+            .map(|&x| eager::unfold(&towards_zero, x, &mut budget))
+            .collect();
+        (eager::sequence_list(ts, &mut budget), budget)
+    });
+    let name = format!("shrink::sequence_list of {} u8", xs.len());
+    report(&name, lazy, eager);
+}
+
+fn main() {
+    bench_vec(10);
+    bench_vec(100);
+    bench_sequence_list(vec![3, 1, 4]);
+    bench_sequence_list(vec![5, 8, 2, 7, 4]);
+    bench_sequence_list((0..50).map(|i| (i * 7 % 11) as u8).collect());
+}
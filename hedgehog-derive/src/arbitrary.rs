@@ -0,0 +1,338 @@
+//! The expansion of `#[derive(Arbitrary)]`.
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::fmt::Display;
+use std::ops::Neg;
+use std::str::FromStr;
+use syn::{
+    parse_quote, Data, DeriveInput, Error, Expr, ExprRange, Fields, GenericParam, Generics, Lit,
+    LitBool, LitStr, RangeLimits, Result, Token, Type, UnOp,
+};
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let path = quote!(#name);
+            gen_fields(name, &path, &data.fields, true)?
+        }
+        Data::Enum(data) => {
+            let mut nonrecs = Vec::new();
+            let mut recs = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let path = quote!(#name::#ident);
+                let g = gen_fields(name, &path, &variant.fields, false)?;
+                let mut recursive = false;
+                for field in &variant.fields {
+                    recursive |= is_recursive(name, field)?;
+                }
+                if recursive {
+                    recs.push(g);
+                } else {
+                    nonrecs.push(g);
+                }
+            }
+            if nonrecs.is_empty() {
+                return Err(Error::new(
+                    name.span(),
+                    "#[derive(Arbitrary)] needs at least one variant that is not recursive",
+                ));
+            }
+            if recs.is_empty() {
+                quote!(::hedgehog::gen::choice(vec![#(#nonrecs),*].into_iter()))
+            } else {
+                quote!(::hedgehog::gen::choice_rec(
+                    vec![#(#nonrecs),*].into_iter(),
+                    vec![#(#recs),*].into_iter(),
+                ))
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                name.span(),
+                "#[derive(Arbitrary)] is not supported for unions",
+            ))
+        }
+    };
+
+    let generics = add_bounds(input.generics.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::hedgehog::arbitrary::Arbitrary for #name #ty_generics #where_clause {
+            fn arbitrary<'__a>() -> ::hedgehog::gen::Gen<'__a, Self>
+            where
+                Self: '__a,
+            {
+                #body
+            }
+        }
+    })
+}
+
+// Every type parameter must itself be `Arbitrary`.
+fn add_bounds(mut generics: Generics) -> Generics {
+    for param in &mut generics.params {
+        if let GenericParam::Type(p) = param {
+            p.bounds
+                .push(parse_quote!(::hedgehog::arbitrary::Arbitrary));
+        }
+    }
+    generics
+}
+
+// Build a `Gen` for a struct or a single variant. The fields are zipped into nested pairs, e.g.
+// `(a, (b, ()))`, which are then taken apart by the constructor. Recursive fields halve the size
+// when `halve` is set, which is left to `gen::choice_rec` for enums.
+fn gen_fields(
+    name: &Ident,
+    path: &TokenStream,
+    fields: &Fields,
+    halve: bool,
+) -> Result<TokenStream> {
+    let binders: Vec<Ident> = (0..fields.len()).map(|i| format_ident!("f{}", i)).collect();
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|f| &f.ident);
+            quote!(#path { #(#idents: #binders),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#binders),*)),
+        Fields::Unit => return Ok(quote!(::hedgehog::gen::constant(#path))),
+    };
+
+    let mut gens = Vec::new();
+    for field in fields {
+        gens.push(gen_field(name, field, halve)?);
+    }
+    let zipped = gens.iter().rev().fold(
+        quote!(::hedgehog::gen::constant(())),
+        |acc, g| quote!(::hedgehog::gen::zip(#g, #acc)),
+    );
+    let pattern = binders
+        .iter()
+        .rev()
+        .fold(quote!(()), |acc, x| quote!((#x, #acc)));
+    Ok(quote!(::hedgehog::gen::map(
+        ::std::rc::Rc::new(|#pattern| #construct),
+        #zipped,
+    )))
+}
+
+// The `#[hedgehog(...)]` attributes of a field.
+struct FieldAttrs {
+    custom: Option<TokenStream>,
+    recursive: Option<bool>,
+}
+
+fn field_attrs(field: &syn::Field) -> Result<FieldAttrs> {
+    let ty = &field.ty;
+    let mut attrs = FieldAttrs {
+        custom: None,
+        recursive: None,
+    };
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("hedgehog")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("recursive") {
+                let recursive = if meta.input.peek(Token![=]) {
+                    let b: LitBool = meta.value()?.parse()?;
+                    b.value
+                } else {
+                    true
+                };
+                attrs.recursive = Some(recursive);
+                return Ok(());
+            }
+            if attrs.custom.is_some() {
+                return Err(meta.error("only one of `gen` or `range` may be given per field"));
+            }
+            if meta.path.is_ident("gen") {
+                let s: LitStr = meta.value()?.parse()?;
+                let e: Expr = s.parse()?;
+                attrs.custom = Some(quote!({
+                    let g: ::hedgehog::gen::Gen<'__a, #ty> = #e;
+                    g
+                }));
+                Ok(())
+            } else if meta.path.is_ident("range") {
+                let s: LitStr = meta.value()?.parse()?;
+                attrs.custom = Some(gen_range(ty, &s)?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `gen = \"...\"`, `range = \"...\"` or `recursive`"))
+            }
+        })?;
+    }
+    Ok(attrs)
+}
+
+// Whether the field refers back to the type being derived, unless its attributes say otherwise.
+fn is_recursive(name: &Ident, field: &syn::Field) -> Result<bool> {
+    let attrs = field_attrs(field)?;
+    Ok(attrs.recursive.unwrap_or_else(|| mentions(name, &field.ty)))
+}
+
+fn gen_field(name: &Ident, field: &syn::Field, halve: bool) -> Result<TokenStream> {
+    let ty = &field.ty;
+    let attrs = field_attrs(field)?;
+    let recursive = attrs.recursive.unwrap_or_else(|| mentions(name, ty));
+    Ok(match attrs.custom {
+        Some(g) => g,
+        // Recursive fields are only built once the generator runs, otherwise constructing the
+        // generator would never finish. Halving the size on the way down makes the sized
+        // generators below, such as `Option` and `Vec`, bottom out.
+        None if recursive => {
+            let g = quote!(::hedgehog::gen::delay(::std::boxed::Box::new(
+                || <#ty as ::hedgehog::arbitrary::Arbitrary>::arbitrary()
+            )));
+            if halve {
+                quote!(::hedgehog::gen::scale(::std::rc::Rc::new(|n: isize| n / 2), #g))
+            } else {
+                g
+            }
+        }
+        None => quote!(<#ty as ::hedgehog::arbitrary::Arbitrary>::arbitrary()),
+    })
+}
+
+fn gen_range(ty: &Type, s: &LitStr) -> Result<TokenStream> {
+    let range: ExprRange = s.parse()?;
+    let (lo, hi) = match (&range.start, &range.end) {
+        (Some(lo), Some(hi)) => (lo, hi),
+        _ => {
+            return Err(Error::new(
+                s.span(),
+                "the range needs both a lower and an upper bound",
+            ))
+        }
+    };
+    let is = |names: &[&str]| match ty {
+        Type::Path(p) => names.iter().any(|n| p.path.is_ident(n)),
+        _ => false,
+    };
+    let half_open = match range.limits {
+        RangeLimits::HalfOpen(_) => true,
+        RangeLimits::Closed(_) => false,
+    };
+    // Only literal bounds can be checked here, anything else is left to the compiler and
+    // `range::constant`.
+    let empty = if is(&["f32", "f64"]) {
+        match (literal::<f64>(lo), literal::<f64>(hi)) {
+            (Some(lo), Some(hi)) => lo > hi || (half_open && lo == hi),
+            _ => false,
+        }
+    } else {
+        match (literal::<i128>(lo), literal::<i128>(hi)) {
+            (Some(lo), Some(hi)) => lo > hi || (half_open && lo == hi),
+            (_, Some(hi)) => half_open && hi == 0 && is(UNSIGNED),
+            _ => false,
+        }
+    };
+    if empty {
+        return Err(Error::new(
+            s.span(),
+            format!("the range `{}` is empty", s.value()),
+        ));
+    }
+    if is(&["f32", "f64"]) {
+        if let RangeLimits::HalfOpen(_) = range.limits {
+            return Err(Error::new(
+                s.span(),
+                "floating point ranges must be inclusive, e.g. `0.0..=1.0`",
+            ));
+        }
+        let f = ty.to_token_stream();
+        Ok(quote!(::hedgehog::gen::#f(::hedgehog::range::constant(#lo, #hi))))
+    } else {
+        let hi = match range.limits {
+            RangeLimits::Closed(_) => quote!(#hi),
+            RangeLimits::HalfOpen(_) => quote!((#hi) - 1),
+        };
+        Ok(quote!(::hedgehog::gen::integral::<#ty>(
+            ::hedgehog::range::constant(#lo, #hi)
+        )))
+    }
+}
+
+const UNSIGNED: &[&str] = &["u8", "u16", "u32", "u64", "u128", "usize"];
+
+// The value of a numeric literal bound, possibly negated or parenthesized.
+fn literal<N>(e: &Expr) -> Option<N>
+where
+    N: FromStr + Neg<Output = N>,
+    N::Err: Display,
+{
+    match e {
+        Expr::Lit(l) => match &l.lit {
+            Lit::Int(i) => i.base10_parse().ok(),
+            Lit::Float(f) => f.base10_parse().ok(),
+            _ => None,
+        },
+        Expr::Unary(u) if matches!(u.op, UnOp::Neg(_)) => literal::<N>(&u.expr).map(|x| -x),
+        Expr::Paren(p) => literal(&p.expr),
+        Expr::Group(g) => literal(&g.expr),
+        _ => None,
+    }
+}
+
+// Whether the type refers back to the type being derived, either by name or through `Self`. Only
+// the identifiers are compared, so a self-reference through a type alias is missed, and an
+// unrelated type that shares the name, e.g. `other::Expr` inside `Expr`, is taken for one. Fields
+// can say otherwise with `#[hedgehog(recursive)]` or `#[hedgehog(recursive = false)]`.
+fn mentions(name: &Ident, ty: &Type) -> bool {
+    fn go(name: &Ident, tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|t| match t {
+            TokenTree::Ident(i) => i == *name || i == "Self",
+            TokenTree::Group(g) => go(name, g.stream()),
+            _ => false,
+        })
+    }
+    go(name, ty.to_token_stream())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        derive(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn empty_and_inverted_ranges_are_rejected() {
+        assert_eq!(
+            error(parse_quote!(
+                struct A(#[hedgehog(range = "5..5")] u8);
+            )),
+            "the range `5..5` is empty"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct A(#[hedgehog(range = "3..=-1")] i32);
+            )),
+            "the range `3..=-1` is empty"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct A(#[hedgehog(range = "LO..0")] usize);
+            )),
+            "the range `LO..0` is empty"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct A(#[hedgehog(range = "1.0..=0.5")] f64);
+            )),
+            "the range `1.0..=0.5` is empty"
+        );
+    }
+
+    #[test]
+    fn nonempty_ranges_are_accepted() {
+        for range in ["-5..-4", "0..=0", "LO..HI", "0..1", "(-1)..=(1)"] {
+            let input: DeriveInput = parse_quote!(
+                struct A(#[hedgehog(range = #range)] i64);
+            );
+            assert!(derive(input).is_ok(), "{}", range);
+        }
+    }
+}
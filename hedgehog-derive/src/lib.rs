@@ -0,0 +1,60 @@
+//! Procedural macros for `hedgehog`, re-exported as `hedgehog::arbitrary::Arbitrary` and
+//! `hedgehog::property`.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+mod arbitrary;
+mod property;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn};
+
+/// Derive `Arbitrary` for a struct or enum.
+///
+/// Every field is generated with its own `Arbitrary` instance, unless overridden with one of the
+/// field attributes below, and the fields are combined with `gen::zip` so that each of them keeps
+/// its integrated shrinking.
+///
+/// * `#[hedgehog(gen = "expr")]` uses `expr`, which must be a `Gen` of the field's type.
+/// * `#[hedgehog(range = "lo..=hi")]` generates the field in the given range, shrinking towards
+///   `lo`. Exclusive ranges `lo..hi` are also accepted for integral fields. Ranges with literal
+///   bounds that are empty, such as `5..5` or `2..=1`, are rejected at compile time.
+/// * `#[hedgehog(recursive)]` and `#[hedgehog(recursive = false)]` say whether the field refers
+///   back to the type being derived. By default this is guessed from the field's type mentioning
+///   the type's name or `Self`, which misses a reference through a type alias and mistakes an
+///   unrelated type of the same name, e.g. `other::Expr` inside `Expr`, for one.
+///
+/// Struct fields that mention the struct itself are recursive and halve the size each time they
+/// are generated, so a field such as `Option<Box<Self>>` or `Vec<Self>` stops after a few levels.
+/// Enums pick one of their variants with `gen::choice`. Variants that mention the type itself are
+/// recursive and are only chosen through `gen::choice_rec`, which halves the size each time one is
+/// taken, so generation always terminates. An enum needs at least one variant that isn't recursive.
+#[proc_macro_derive(Arbitrary, attributes(hedgehog))]
+pub fn derive_arbitrary(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match arbitrary::derive(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Turn a function into a `#[test]` that checks it as a property.
+///
+/// Each argument is generated with its `Arbitrary` instance, or with the generator given by a
+/// `#[gen = "expr"]` attribute on the argument, and the shrunk arguments are reported when the
+/// property fails. The function may return `()`, `bool`, a `Property<()>` or a `Result<(), E>`;
+/// panics count as failures.
+///
+/// The number of tests, discards and shrinks can be set with
+/// `#[property(tests = 1000, discards = 10, shrinks = 100)]`.
+#[proc_macro_attribute]
+pub fn property(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    match property::expand(args.into(), input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
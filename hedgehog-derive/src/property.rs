@@ -0,0 +1,128 @@
+//! The expansion of `#[property]`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::Parser;
+use syn::{Error, Expr, ExprLit, FnArg, ItemFn, Lit, LitInt, Result};
+
+pub fn expand(args: TokenStream, mut item: ItemFn) -> Result<TokenStream> {
+    let mut config = quote!(::hedgehog::property::config::default());
+    let parser = syn::meta::parser(|meta| {
+        let with = if meta.path.is_ident("tests") {
+            quote!(with_tests)
+        } else if meta.path.is_ident("discards") {
+            quote!(with_discards)
+        } else if meta.path.is_ident("shrinks") {
+            quote!(with_shrinks)
+        } else {
+            return Err(meta.error("expected `tests`, `discards` or `shrinks`"));
+        };
+        let n: LitInt = meta.value()?.parse()?;
+        config = quote!(::hedgehog::property::config::#with(#n, #config));
+        Ok(())
+    });
+    parser.parse2(args)?;
+
+    let sig = &item.sig;
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &sig.generics,
+            "#[property] functions cannot be generic",
+        ));
+    }
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(Error::new_spanned(
+            asyncness,
+            "#[property] functions cannot be async",
+        ));
+    }
+
+    let mut binders: Vec<Ident> = Vec::new();
+    let mut labels = Vec::new();
+    let mut types = Vec::new();
+    let mut gens = Vec::new();
+    for (i, input) in item.sig.inputs.iter_mut().enumerate() {
+        let arg = match input {
+            FnArg::Typed(arg) => arg,
+            FnArg::Receiver(r) => {
+                return Err(Error::new_spanned(
+                    r,
+                    "#[property] functions cannot take self",
+                ))
+            }
+        };
+        let ty = &arg.ty;
+        let mut gen = quote!(<#ty as ::hedgehog::arbitrary::Arbitrary>::arbitrary());
+        let mut attrs = Vec::new();
+        for attr in arg.attrs.drain(..) {
+            if !attr.path().is_ident("gen") {
+                attrs.push(attr);
+                continue;
+            }
+            let e = match &attr.meta.require_name_value()?.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => s.parse::<Expr>()?,
+                other => return Err(Error::new_spanned(other, "expected `#[gen = \"...\"]`")),
+            };
+            gen = quote!({
+                let g: ::hedgehog::gen::Gen<#ty> = #e;
+                g
+            });
+        }
+        arg.attrs = attrs;
+        binders.push(format_ident!("__arg{}", i));
+        labels.push(arg.pat.to_token_stream().to_string());
+        types.push(ty.clone());
+        gens.push(gen);
+    }
+
+    // The arguments are generated as nested pairs, e.g. `(x, (xs, ()))`, so that each of them
+    // shrinks on its own.
+    let zipped = gens.iter().rev().fold(
+        quote!(::hedgehog::gen::constant(())),
+        |acc, g| quote!(::hedgehog::gen::zip(#g, #acc)),
+    );
+    let pattern = binders
+        .iter()
+        .rev()
+        .fold(quote!(()), |acc, x| quote!((#x, #acc)));
+    let nested_types = types
+        .iter()
+        .rev()
+        .fold(quote!(()), |acc, t| quote!((#t, #acc)));
+    let ref_pattern = binders
+        .iter()
+        .rev()
+        .fold(quote!(()), |acc, x| quote!((ref #x, #acc)));
+
+    let attrs = std::mem::take(&mut item.attrs);
+    let vis = &item.vis;
+    let name = &item.sig.ident;
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #name() {
+            #item
+
+            // A function rather than a closure, so that the property can borrow it for `'static`
+            // when the body itself returns a `Property<'static, ()>`.
+            fn __property(#pattern: #nested_types) -> ::hedgehog::property::Property<'static, ()> {
+                ::hedgehog::property::property::IntoProperty::into_property(#name(#(#binders),*))
+            }
+            ::hedgehog::property::property::check_with(
+                #config,
+                ::hedgehog::property::property::for_all_rendered(
+                    #zipped,
+                    |&#ref_pattern| {
+                        let args: Vec<String> = vec![
+                            #(format!("{} = {:?}", #labels, #binders)),*
+                        ];
+                        args.join("\n")
+                    },
+                    &__property,
+                ),
+            )
+        }
+    })
+}
@@ -0,0 +1,201 @@
+extern crate hedgehog;
+
+use hedgehog::arbitrary::Arbitrary;
+use hedgehog::gen;
+use hedgehog::property::{journal, property, Property, Status};
+use hedgehog::range;
+use hedgehog::range::Size;
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct Unit;
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct Point(i32, i32);
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct Person {
+    name: String,
+    #[hedgehog(range = "0..=120")]
+    age: u8,
+    #[hedgehog(range = "1..10")]
+    rating: i64,
+    #[hedgehog(range = "0.0..=1.0")]
+    score: f64,
+    #[hedgehog(gen = "gen::vec(range::constant(1, 3), gen::lower())")]
+    initials: Vec<char>,
+}
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct Pair<A, B> {
+    left: A,
+    right: B,
+}
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+enum Shape {
+    Empty,
+    Circle(#[hedgehog(range = "1..=10")] u32),
+    Rect { w: u8, h: u8 },
+}
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+enum Expr {
+    Lit(u8),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Self>),
+}
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct List {
+    head: u8,
+    tail: Option<Box<List>>,
+}
+
+// Recursive through an alias, which the derive cannot see without the attribute.
+type Forest = Vec<Rose>;
+
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+struct Rose {
+    #[hedgehog(recursive)]
+    children: Forest,
+}
+
+mod other {
+    #[derive(hedgehog::arbitrary::Arbitrary, Clone, Debug, PartialEq)]
+    pub struct Label(pub bool);
+}
+
+// Not recursive, even though the field mentions a type called `Label`.
+#[derive(Arbitrary, Clone, Debug, PartialEq)]
+enum Label {
+    Plain,
+    Tagged(#[hedgehog(recursive = false)] other::Label),
+}
+
+fn sample<'a, A>() -> Vec<A>
+where
+    A: Arbitrary + 'a,
+{
+    gen::sample(Size(30), 100, A::arbitrary())
+}
+
+// The shrunk counterexample of a property that is expected to fail.
+fn counterexample(p: Property<()>) -> Vec<String> {
+    match property::report(p).status {
+        Status::Failed(failure) => journal::to_vec(failure.journal),
+        _ => panic!("expected the property to be falsified"),
+    }
+}
+
+fn depth(e: &Expr) -> usize {
+    match e {
+        Expr::Lit(_) => 1,
+        Expr::Neg(x) => 1 + depth(x),
+        Expr::Add(x, y) => 1 + depth(x).max(depth(y)),
+    }
+}
+
+#[test]
+fn unit_and_tuple_structs_can_be_derived() {
+    assert!(sample::<Unit>().iter().all(|x| *x == Unit));
+    assert_eq!(sample::<Point>().len(), 100);
+}
+
+#[test]
+fn field_attributes_override_the_generator() {
+    for p in sample::<Person>() {
+        assert!(p.age <= 120);
+        assert!(p.rating >= 1 && p.rating < 10);
+        assert!(p.score >= 0.0 && p.score <= 1.0);
+        assert!(!p.initials.is_empty() && p.initials.len() <= 3);
+        assert!(p.initials.iter().all(|c| c.is_ascii_lowercase()));
+    }
+}
+
+#[test]
+fn generic_structs_require_arbitrary_parameters() {
+    assert_eq!(sample::<Pair<bool, Option<u16>>>().len(), 100);
+}
+
+#[test]
+fn every_variant_is_generated() {
+    let xs = sample::<Shape>();
+    assert!(xs.contains(&Shape::Empty));
+    assert!(xs.iter().any(|x| matches!(x, Shape::Circle(_))));
+    assert!(xs.iter().any(|x| matches!(x, Shape::Rect { .. })));
+    assert!(xs.iter().all(|x| match x {
+        Shape::Circle(r) => (1..=10).contains(r),
+        _ => true,
+    }));
+}
+
+#[test]
+fn recursive_variants_terminate_and_are_chosen() {
+    let xs = gen::sample(Size(99), 200, Expr::arbitrary());
+    assert!(xs.iter().any(|x| depth(x) > 1));
+    // Each recursive step halves the size, so the depth is logarithmic in it.
+    assert!(xs.iter().all(|x| depth(x) <= 8));
+}
+
+#[test]
+fn recursive_structs_terminate() {
+    let len = |mut l: &List| {
+        let mut n = 1;
+        while let Some(tail) = &l.tail {
+            l = tail;
+            n += 1;
+        }
+        n
+    };
+    let xs = gen::sample(Size(99), 200, List::arbitrary());
+    assert!(xs.iter().any(|x| len(x) > 1));
+    // The size halves with every cell, so only the last few cells risk going on.
+    assert!(xs.iter().all(|x| len(x) <= 40));
+}
+
+#[test]
+fn recursion_can_be_declared_on_fields() {
+    fn size(r: &Rose) -> usize {
+        1 + r.children.iter().map(size).sum::<usize>()
+    }
+    let xs = gen::sample(Size(30), 50, Rose::arbitrary());
+    assert!(xs.iter().any(|x| size(x) > 1));
+    assert!(xs.iter().all(|x| size(x) < 10_000));
+    // `choice_rec` only picks recursive variants above size 1.
+    let xs = gen::sample(Size(1), 100, Label::arbitrary());
+    assert!(xs.iter().any(|x| matches!(x, Label::Tagged(_))));
+}
+
+#[test]
+fn derived_fields_shrink_independently() {
+    let prop = |p: Point| property::from_bool(p.0 < 10 || p.1 < 20);
+    assert_eq!(
+        counterexample(property::for_all_arbitrary(&prop)),
+        vec!["Point(10, 20)"]
+    );
+}
+
+#[test]
+fn recursive_types_shrink_to_the_smallest_counterexample() {
+    let prop = |e: Expr| property::from_bool(depth(&e) < 3);
+    // Any expression of depth 3 with only zero literals is minimal, e.g.
+    // `Neg(Neg(Lit(0)))` or `Add(Lit(0), Neg(Lit(0)))`.
+    let shown = counterexample(property::for_all_arbitrary(&prop)).concat();
+    let nesting = shown
+        .chars()
+        .scan(0, |n, c| {
+            match c {
+                '(' => *n += 1,
+                ')' => *n -= 1,
+                _ => (),
+            }
+            Some(*n)
+        })
+        .max();
+    assert_eq!(nesting, Some(3), "{}", shown);
+    assert!(
+        !shown.chars().any(|c| c.is_ascii_digit() && c != '0'),
+        "{}",
+        shown
+    );
+}
@@ -0,0 +1,72 @@
+extern crate hedgehog;
+
+use hedgehog::property::Property;
+use hedgehog::{gen, property, range};
+use std::cell::Cell;
+
+#[property]
+fn reversing_twice_is_the_identity(xs: Vec<u8>) {
+    let mut ys = xs.clone();
+    ys.reverse();
+    ys.reverse();
+    assert_eq!(xs, ys);
+}
+
+#[property]
+fn addition_commutes(x: u16, y: u16) -> bool {
+    u32::from(x) + u32::from(y) == u32::from(y) + u32::from(x)
+}
+
+#[property]
+fn custom_generators_are_used(#[gen = "gen::u8(range::constant(10, 20))"] x: u8, flag: bool) {
+    assert!((10..=20).contains(&x), "{} {}", x, flag);
+}
+
+#[property]
+fn properties_can_be_returned(x: i8) -> Property<'static, ()> {
+    property::property::from_bool(i16::from(x).abs() <= 128)
+}
+
+#[property]
+fn results_can_be_returned(x: u32) -> Result<(), String> {
+    x.checked_add(0)
+        .map(|_| ())
+        .ok_or_else(|| "overflow".to_string())
+}
+
+thread_local! {
+    static RUNS: Cell<usize> = const { Cell::new(0) };
+}
+
+// Fails on the 51st run, so it only passes when fewer tests are run.
+#[property(tests = 50, discards = 10, shrinks = 0)]
+fn the_number_of_tests_can_be_set(_x: bool) -> bool {
+    RUNS.with(|n| {
+        n.set(n.get() + 1);
+        n.get() <= 50
+    })
+}
+
+#[property]
+#[should_panic(expected = "x = 10\ny = 20")]
+fn failures_report_the_shrunk_arguments(x: u8, y: u8) -> bool {
+    x < 10 || y < 20
+}
+
+#[property]
+#[should_panic(expected = "xs = [0, 0, 0]\n*** Panicked: too long")]
+fn panics_are_reported_after_the_arguments(xs: Vec<u8>) {
+    if xs.len() >= 3 {
+        panic!("too long")
+    }
+}
+
+#[property]
+#[should_panic(expected = "*** Error: \"odd\"")]
+fn errors_are_recorded_in_the_journal(x: u8) -> Result<(), String> {
+    if x % 2 == 1 {
+        Err("odd".to_string())
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+#!/usr/bin/env python3
+"""Regenerate the tables at the top of src/unicode.rs from the Unicode Character Database.
+
+Download UnicodeData.txt, Scripts.txt and Blocks.txt from
+https://www.unicode.org/Public/14.0.0/ucd/ (or the directory of a newer version, updating
+UNICODE_VERSION below) into one directory, then run from the root of the repository:
+
+    python3 scripts/unicode_tables.py path/to/ucd
+
+Everything in src/unicode.rs above the END line is replaced, everything below it, i.e. the hand
+picked INTERESTING table, block() and the tests, is kept. The file is formatted with rustfmt
+afterwards.
+"""
+
+import os
+import re
+import subprocess
+import sys
+
+UNICODE_VERSION = "14.0.0"
+TARGET = "src/unicode.rs"
+END = "// End of the tables generated by scripts/unicode_tables.py.\n"
+SURROGATES = (0xD800, 0xDFFF)
+SCRIPTS = [
+    "Latin",
+    "Greek",
+    "Cyrillic",
+    "Armenian",
+    "Hebrew",
+    "Arabic",
+    "Devanagari",
+    "Bengali",
+    "Tamil",
+    "Thai",
+    "Georgian",
+    "Hangul",
+    "Hiragana",
+    "Katakana",
+    "Han",
+    "Ethiopic",
+    "Common",
+    "Inherited",
+]
+
+HEADER = """\
+//! Character tables from the Unicode Character Database, version {version}, embedded so that the
+//! character generators in `gen` work offline. Every table is a sorted list of disjoint, inclusive
+//! ranges of characters.
+//!
+//! Everything down to `BLOCKS` is generated by `scripts/unicode_tables.py` from `UnicodeData.txt`,
+//! `Scripts.txt` and `Blocks.txt` of <https://www.unicode.org/Public/{version}/ucd/>. Regenerate
+//! those tables rather than edit them when moving to a newer version of Unicode. `INTERESTING` is
+//! picked by hand.
+"""
+
+
+def unicode_data(path):
+    """Map every assigned code point to its general category and decomposition mapping."""
+    props = {}
+    first = None
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            fields = line.rstrip("\n").split(";")
+            cp = int(fields[0], 16)
+            if fields[1].endswith(", First>"):
+                first = cp
+                continue
+            lo = first if fields[1].endswith(", Last>") else cp
+            for c in range(lo, cp + 1):
+                props[c] = (fields[2], fields[5])
+            first = None
+    return props
+
+
+def merge(cps):
+    ranges = []
+    for cp in sorted(cps):
+        if SURROGATES[0] <= cp <= SURROGATES[1]:
+            continue
+        if ranges and ranges[-1][1] == cp - 1:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp])
+    return ranges
+
+
+def scripts(path):
+    cps = {name: [] for name in SCRIPTS}
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#")[0].strip()
+            if not line:
+                continue
+            span, name = [x.strip() for x in line.split(";")]
+            if name not in cps:
+                continue
+            lo, _, hi = span.partition("..")
+            cps[name].extend(range(int(lo, 16), int(hi or lo, 16) + 1))
+    return {name: merge(xs) for name, xs in cps.items()}
+
+
+def blocks(path):
+    out = []
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            m = re.match(r"([0-9A-F]+)\.\.([0-9A-F]+); (.*)", line.strip())
+            if m and "Surrogates" not in m[3]:
+                out.append((m[3], int(m[1], 16), int(m[2], 16)))
+    return out
+
+
+def char(cp):
+    return "'\\u{%x}'" % cp
+
+
+def table(ranges):
+    return "\n".join("    (%s, %s)," % (char(lo), char(hi)) for lo, hi in ranges)
+
+
+def const(doc, vis, name, ranges):
+    docs = "".join("/// %s\n" % line for line in doc) if doc else ""
+    return "%s%sconst %s: &[(char, char)] = &[\n%s\n];\n" % (docs, vis, name, table(ranges))
+
+
+def generate(ucd):
+    props = unicode_data(os.path.join(ucd, "UnicodeData.txt"))
+
+    def category(pred):
+        return merge(cp for cp, (gc, _) in props.items() if pred(gc))
+
+    def decomposition(pred):
+        return merge(cp for cp, (_, d) in props.items() if pred(cp, d))
+
+    categories = [
+        ("LETTER", ["Letters, general categories `Lu`, `Ll`, `Lt`, `Lm` and `Lo`."], "L"),
+        ("MARK", ["Combining marks, general categories `Mn`, `Mc` and `Me`."], "M"),
+        ("NUMBER", ["Numbers, general categories `Nd`, `Nl` and `No`."], "N"),
+        (
+            "PUNCTUATION",
+            ["Punctuation, general categories `Pc`, `Pd`, `Ps`, `Pe`, `Pi`, `Pf` and `Po`."],
+            "P",
+        ),
+        (
+            "SYMBOL",
+            ["Symbols, general categories `Sm`, `Sc`, `Sk` and `So`, which include the emoji."],
+            "S",
+        ),
+        ("SEPARATOR", ["Separators, general categories `Zs`, `Zl` and `Zp`."], "Z"),
+        ("CONTROL", ["Control characters, general category `Cc`."], "Cc"),
+        (
+            "FORMAT",
+            [
+                "Format characters, general category `Cf`, such as zero width joiners and bidi "
+                "controls."
+            ],
+            "Cf",
+        ),
+    ]
+    out = [HEADER.format(version=UNICODE_VERSION)]
+    for name, doc, prefix in categories:
+        out.append(const(doc, "pub ", name, category(lambda gc: gc.startswith(prefix))))
+    out.append(
+        const(
+            [
+                "Characters with a canonical decomposition, whose NFC and NFD forms differ. Hangul",
+                "syllables are left out since they decompose algorithmically.",
+            ],
+            "pub ",
+            "DECOMPOSABLE",
+            decomposition(
+                lambda cp, d: d and not d.startswith("<") and not 0xAC00 <= cp <= 0xD7A3
+            ),
+        )
+    )
+    out.append(
+        const(
+            [
+                "Characters with a compatibility decomposition, which NFKC and NFKD change, e.g. "
+                "ligatures,",
+                "full width forms and superscripts.",
+            ],
+            "pub ",
+            "COMPATIBILITY",
+            decomposition(lambda cp, d: d.startswith("<")),
+        )
+    )
+
+    out.append(
+        "/// A selection of scripts, from the Unicode `Script` property.\n"
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n"
+        "pub enum Script {\n"
+        + "".join("    %s,\n" % name for name in SCRIPTS)
+        + "}\n\n"
+        "impl Script {\n"
+        "    /// The characters of this script.\n"
+        "    pub fn ranges(self) -> &'static [(char, char)] {\n"
+        "        match self {\n"
+        + "".join("            Script::%s => %s,\n" % (name, name.upper()) for name in SCRIPTS)
+        + "        }\n"
+        "    }\n"
+        "}\n"
+    )
+    for name, ranges in scripts(os.path.join(ucd, "Scripts.txt")).items():
+        out.append(const(None, "", name.upper(), ranges))
+
+    out.append(
+        "/// Every block as `(name, first, last)`, except the surrogate blocks which hold no "
+        "`char`s.\n"
+        "pub const BLOCKS: &[(&str, char, char)] = &[\n"
+        + "".join(
+            '    ("%s", %s, %s),\n' % (name, char(lo), char(hi))
+            for name, lo, hi in blocks(os.path.join(ucd, "Blocks.txt"))
+        )
+        + "];\n"
+    )
+    return "\n".join(out)
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: %s path/to/ucd" % sys.argv[0])
+    with open(TARGET, encoding="utf-8") as f:
+        current = f.read()
+    if END not in current:
+        sys.exit("%s: missing the line %r" % (TARGET, END.strip()))
+    kept = current[current.index(END) :]
+    with open(TARGET, "w", encoding="utf-8") as f:
+        f.write(generate(sys.argv[1]) + "\n" + kept)
+    subprocess.run(["rustfmt", "--edition", "2015", TARGET], check=True)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,241 @@
+use crate::gen;
+use crate::gen::Gen;
+use crate::range;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::hash::Hash;
+use std::rc::Rc;
+
+pub use hedgehog_derive::Arbitrary;
+
+/// Types with a default generator, so that properties can be written without assembling a `Gen`
+/// by hand. See `property::for_all_arbitrary`.
+pub trait Arbitrary: Clone {
+    fn arbitrary<'a>() -> Gen<'a, Self>
+    where
+        Self: 'a;
+}
+
+// The length of generated strings and collections.
+fn length<'a>() -> range::Range<'a, usize> {
+    range::linear(0, 100)
+}
+
+macro_rules! arbitrary_integral {
+    ($($t:ident),*) => {
+        $(
+            impl Arbitrary for $t {
+                fn arbitrary<'a>() -> Gen<'a, Self> {
+                    gen::$t(range::constant_bounded())
+                }
+            }
+        )*
+    };
+}
+
+arbitrary_integral!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+impl Arbitrary for f64 {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::f64(range::constant_from(0.0, -1.0e6, 1.0e6))
+    }
+}
+
+impl Arbitrary for f32 {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::f32(range::constant_from(0.0, -1.0e6, 1.0e6))
+    }
+}
+
+impl Arbitrary for bool {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::bool()
+    }
+}
+
+impl Arbitrary for char {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::unicode()
+    }
+}
+
+impl Arbitrary for () {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::constant(())
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary<'a>() -> Gen<'a, Self> {
+        gen::string(length(), gen::unicode())
+    }
+}
+
+impl<A> Arbitrary for Option<A>
+where
+    A: Arbitrary,
+{
+    fn arbitrary<'a>() -> Gen<'a, Self>
+    where
+        Self: 'a,
+    {
+        gen::option(A::arbitrary())
+    }
+}
+
+impl<A, E> Arbitrary for Result<A, E>
+where
+    A: Arbitrary,
+    E: Arbitrary,
+{
+    fn arbitrary<'a>() -> Gen<'a, Self>
+    where
+        Self: 'a,
+    {
+        gen::choice(
+            vec![
+                gen::map(Rc::new(Ok), A::arbitrary()),
+                gen::map(Rc::new(Err), E::arbitrary()),
+            ]
+            .into_iter(),
+        )
+    }
+}
+
+impl<A> Arbitrary for Box<A>
+where
+    A: Arbitrary,
+{
+    fn arbitrary<'a>() -> Gen<'a, Self>
+    where
+        Self: 'a,
+    {
+        gen::map(Rc::new(Box::new), A::arbitrary())
+    }
+}
+
+impl<A> Arbitrary for Vec<A>
+where
+    A: Arbitrary,
+{
+    fn arbitrary<'a>() -> Gen<'a, Self>
+    where
+        Self: 'a,
+    {
+        gen::vec(length(), A::arbitrary())
+    }
+}
+
+// The remaining collections are built from a generated `Vec`, so they shrink the same way.
+macro_rules! arbitrary_collection {
+    ($c:ident < $($p:ident),* > where $($bound:tt)*) => {
+        impl<$($p),*> Arbitrary for $c<$($p),*>
+        where
+            $($bound)*
+        {
+            fn arbitrary<'a>() -> Gen<'a, Self>
+            where
+                Self: 'a,
+            {
+                gen::map(
+                    Rc::new(|xs: Vec<_>| xs.into_iter().collect()),
+                    Vec::arbitrary(),
+                )
+            }
+        }
+    };
+}
+
+arbitrary_collection!(VecDeque<A> where A: Arbitrary);
+arbitrary_collection!(LinkedList<A> where A: Arbitrary);
+arbitrary_collection!(BinaryHeap<A> where A: Arbitrary + Ord);
+arbitrary_collection!(BTreeSet<A> where A: Arbitrary + Ord);
+arbitrary_collection!(HashSet<A> where A: Arbitrary + Eq + Hash);
+arbitrary_collection!(BTreeMap<K, V> where K: Arbitrary + Ord, V: Arbitrary);
+arbitrary_collection!(HashMap<K, V> where K: Arbitrary + Eq + Hash, V: Arbitrary);
+
+// Tuples are generated as nested pairs, e.g. `(a, (b, (c, ())))`, and then flattened.
+macro_rules! nested_zip {
+    () => { gen::constant(()) };
+    ($t:ident $(, $rest:ident)*) => { gen::zip($t::arbitrary(), nested_zip!($($rest),*)) };
+}
+
+macro_rules! nested_pat {
+    () => { () };
+    ($x:ident $(, $rest:ident)*) => { ($x, nested_pat!($($rest),*)) };
+}
+
+macro_rules! arbitrary_tuple {
+    ($($t:ident $x:ident),+) => {
+        impl<$($t),+> Arbitrary for ($($t,)+)
+        where
+            $($t: Arbitrary),+
+        {
+            fn arbitrary<'a>() -> Gen<'a, Self>
+            where
+                Self: 'a,
+            {
+                gen::map(
+                    Rc::new(|nested_pat!($($x),+)| ($($x,)+)),
+                    nested_zip!($($t),+),
+                )
+            }
+        }
+    };
+}
+
+arbitrary_tuple!(A a);
+arbitrary_tuple!(A a, B b);
+arbitrary_tuple!(A a, B b, C c);
+arbitrary_tuple!(A a, B b, C c, D d);
+arbitrary_tuple!(A a, B b, C c, D d, E e);
+arbitrary_tuple!(A a, B b, C c, D d, E e, F f);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::property::property;
+    use crate::property::testing::shrinks_to;
+    use crate::range::Size;
+
+    fn sample<'a, A>() -> Vec<A>
+    where
+        A: Arbitrary + 'a,
+    {
+        gen::sample(Size(30), 20, A::arbitrary())
+    }
+
+    #[test]
+    fn primitives_can_be_sampled() {
+        assert_eq!(sample::<u8>().len(), 20);
+        assert_eq!(sample::<i64>().len(), 20);
+        assert_eq!(sample::<u64>().len(), 20);
+        assert_eq!(sample::<u128>().len(), 20);
+        assert_eq!(sample::<i128>().len(), 20);
+        assert_eq!(sample::<f64>().len(), 20);
+        assert_eq!(sample::<char>().len(), 20);
+        assert_eq!(sample::<bool>().len(), 20);
+        assert!(sample::<f32>().iter().all(|x| x.abs() <= 1.0e6));
+    }
+
+    #[test]
+    fn collections_respect_the_length_range() {
+        assert!(sample::<Vec<u8>>().iter().all(|xs| xs.len() <= 100));
+        assert!(sample::<String>().iter().all(|s| s.chars().count() <= 100));
+        assert!(sample::<HashMap<u8, bool>>().iter().all(|m| m.len() <= 100));
+        assert!(sample::<BTreeSet<i32>>().iter().all(|s| s.len() <= 100));
+    }
+
+    #[test]
+    fn tuples_are_generated_component_wise() {
+        let xs = sample::<(u8, bool, Option<char>)>();
+        assert_eq!(xs.len(), 20);
+        let ys = sample::<(Result<u8, i8>, Box<u16>)>();
+        assert_eq!(ys.len(), 20);
+    }
+
+    #[test]
+    fn for_all_arbitrary_shrinks_to_a_minimal_counterexample() {
+        let prop = |xs: Vec<u8>| property::from_bool(xs.len() < 3);
+        shrinks_to(property::for_all_arbitrary(&prop), vec!["[0, 0, 0]"]);
+    }
+}
@@ -2,13 +2,23 @@ use crate::random;
 use crate::random::Random;
 use crate::range;
 use crate::range::{Range, Size};
+use crate::regex;
+use crate::regex::Regex;
 use crate::seed;
 use crate::seed::Seed;
 use crate::shrink;
 use crate::tree;
 use crate::tree::Tree;
-use num::{FromPrimitive, Integer, ToPrimitive};
+use crate::unicode;
+use crate::unicode::Script;
+use num::bigint::{BigInt, BigUint};
+use num::rational::BigRational;
+use num::{Float, FromPrimitive, Integer};
+use rand::distributions::uniform::SampleUniform;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::convert::TryInto;
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -25,13 +35,73 @@ where
     Gen(r)
 }
 
-pub fn to_random<'a, A>(g: Gen<'a, A>) -> Random<Tree<'a, A>>
+pub fn to_random<'a, A>(g: Gen<'a, A>) -> Random<'a, Tree<'a, A>>
 where
     A: Clone,
 {
     g.0
 }
 
+/// Method versions of the combinators below, taking plain closures so that generators can be
+/// written as a chain, e.g. `gen::u8(range).filter(|x| x % 2 == 0).vec(range::linear(0, 10))`.
+impl<'a, A> Gen<'a, A>
+where
+    A: Clone + 'a,
+{
+    pub fn map<F, B>(self, f: F) -> Gen<'a, B>
+    where
+        B: Clone + 'a,
+        F: Fn(A) -> B + 'a,
+    {
+        map(Rc::new(f), self)
+    }
+
+    pub fn bind<F, B>(self, k: F) -> Gen<'a, B>
+    where
+        B: Clone + 'a,
+        F: Fn(A) -> Gen<'a, B> + 'a,
+    {
+        bind(self, Rc::new(k))
+    }
+
+    pub fn filter<F>(self, p: F) -> Gen<'a, A>
+    where
+        F: Fn(A) -> bool + 'a,
+    {
+        filter(Rc::new(p), self)
+    }
+
+    pub fn zip<B>(self, other: Gen<'a, B>) -> Gen<'a, (A, B)>
+    where
+        B: Clone + 'a,
+    {
+        zip(self, other)
+    }
+
+    pub fn option(self) -> Gen<'a, Option<A>> {
+        option(self)
+    }
+
+    pub fn vec(self, range: Range<'a, usize>) -> Gen<'a, Vec<A>> {
+        vec(range, self)
+    }
+
+    pub fn no_shrink(self) -> Gen<'a, A> {
+        no_shrink(self)
+    }
+
+    pub fn resize(self, new_size: isize) -> Gen<'a, A> {
+        resize(new_size, self)
+    }
+
+    pub fn scale<F>(self, f: F) -> Gen<'a, A>
+    where
+        F: Fn(isize) -> isize + 'a,
+    {
+        scale(Rc::new(f), self)
+    }
+}
+
 pub fn delay<'a, A>(f: Box<dyn Fn() -> Gen<'a, A> + 'a>) -> Gen<'a, A>
 where
     A: Clone + 'a,
@@ -46,7 +116,7 @@ where
     F: Fn(A) -> Vec<A> + 'a,
 {
     let expand = Rc::new(move |x| x);
-    let shrink: Rc<F> = shrink.into();
+    let shrink: Rc<F> = shrink;
     from_random(random::map(
         Rc::new(move |x| tree::unfold(expand.clone(), shrink.clone(), x)),
         random,
@@ -60,13 +130,7 @@ where
     A: Clone + 'a,
     B: Clone + 'a,
 {
-    bind(
-        gf,
-        Rc::new(move |f: F| {
-            let gx = gx.clone();
-            bind(gx, Rc::new(move |x| constant(f(x))))
-        }),
-    )
+    map2(Rc::new(|f: F, x| f(x)), gf, gx)
 }
 
 pub fn constant<'a, A>(x: A) -> Gen<'a, A>
@@ -105,7 +169,6 @@ where
     from_random(f(to_random(g)))
 }
 
-// TODO: Arbitrary levels of maps a la `apply!`?
 pub fn map<'a, F, A, B>(f: Rc<F>, g: Gen<'a, A>) -> Gen<'a, B>
 where
     A: Clone + 'a,
@@ -115,7 +178,6 @@ where
     map_tree(Rc::new(move |x| tree::map(f.clone(), x)), g)
 }
 
-// TODO: Turn map into a macro? e.g. map! that is variadic.
 pub fn map2<'a, F, A, B, C>(f: Rc<F>, gx: Gen<'a, A>, gy: Gen<'a, B>) -> Gen<'a, C>
 where
     A: Clone + 'a,
@@ -123,29 +185,25 @@ where
     C: Clone + 'a,
     F: Fn(A, B) -> C + 'a,
 {
-    bind(
-        gx,
-        Rc::new(move |x: A| {
-            let f = f.clone();
-            let gy = gy.clone();
-            bind(
-                gy,
-                Rc::new(move |y: B| {
-                    let x = x.clone();
-                    let y = y.clone();
-                    constant(f(x, y))
-                }),
-            )
-        }),
-    )
+    map(Rc::new(move |(x, y)| f(x, y)), zip(gx, gy))
 }
 
+/// Generate a pair whose components shrink independently of each other, see `tree::zip`. The
+/// components are generated from the two halves of a split seed, just as `bind` would.
 pub fn zip<'a, A, B>(gx: Gen<'a, A>, gy: Gen<'a, B>) -> Gen<'a, (A, B)>
 where
     A: Clone + 'a,
     B: Clone + 'a,
 {
-    map2(Rc::new(move |x, y| (x, y)), gx, gy)
+    let rx = to_random(gx);
+    let ry = to_random(gy);
+    from_random(Rc::new(move |seed0, size| {
+        let (seed1, seed2) = seed::split(seed0);
+        tree::zip(
+            random::run(seed1, size, rx.clone()),
+            random::run(seed2, size, ry.clone()),
+        )
+    }))
 }
 
 pub fn tuple<'a, A>(g: Gen<'a, A>) -> Gen<'a, (A, A)>
@@ -157,6 +215,158 @@ where
     zip(g1, g2)
 }
 
+// Larger products are built from nested pairs, e.g. `(a, (b, c))`, so every component keeps
+// shrinking on its own.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! zip_nested(
+    ($g:expr) => { $g };
+    ($g:expr, $($rest:expr),+) => { $crate::gen::zip($g, $crate::zip_nested!($($rest),+)) };
+);
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! pat_nested(
+    ($x:ident) => { $x };
+    ($x:ident, $($rest:ident),+) => { ($x, $crate::pat_nested!($($rest),+)) };
+);
+
+macro_rules! replace_ty(
+    ($_x:ident, $t:ty) => { $t };
+);
+
+macro_rules! replace_expr(
+    ($_x:ident, $e:expr) => { $e };
+);
+
+macro_rules! map_n(
+    ($name:ident, $($t:ident $x:ident $g:ident),+) => {
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<'a, F, $($t,)+ R>(f: Rc<F>, $($g: Gen<'a, $t>),+) -> Gen<'a, R>
+        where
+            $($t: Clone + 'a,)+
+            R: Clone + 'a,
+            F: Fn($($t),+) -> R + 'a,
+        {
+            map(Rc::new(move |pat_nested!($($x),+)| f($($x),+)), zip_nested!($($g),+))
+        }
+    };
+);
+
+map_n!(map3, A a ga, B b gb, C c gc);
+map_n!(map4, A a ga, B b gb, C c gc, D d gd);
+map_n!(map5, A a ga, B b gb, C c gc, D d gd, E e ge);
+map_n!(map6, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg);
+map_n!(map7, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh);
+map_n!(map8, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi);
+
+macro_rules! zip_n(
+    ($name:ident, $($t:ident $x:ident $g:ident),+) => {
+        #[allow(clippy::too_many_arguments)]
+        pub fn $name<'a, $($t),+>($($g: Gen<'a, $t>),+) -> Gen<'a, ($($t,)+)>
+        where
+            $($t: Clone + 'a,)+
+        {
+            map(Rc::new(|pat_nested!($($x),+)| ($($x,)+)), zip_nested!($($g),+))
+        }
+    };
+);
+
+zip_n!(zip3, A a ga, B b gb, C c gc);
+zip_n!(zip4, A a ga, B b gb, C c gc, D d gd);
+zip_n!(zip5, A a ga, B b gb, C c gc, D d gd, E e ge);
+zip_n!(zip6, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg);
+zip_n!(zip7, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh);
+zip_n!(zip8, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi);
+zip_n!(zip9, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi, J j gj);
+zip_n!(zip10, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi, J j gj, K k gk);
+zip_n!(
+    zip11, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi, J j gj, K k gk, L l gl
+);
+zip_n!(
+    zip12, A a ga, B b gb, C c gc, D d gd, E e ge, G g gg, H h gh, I i gi, J j gj, K k gk, L l gl,
+    M m gm
+);
+
+// Pairs each generator with a binder named `x`. Every `x` comes from a separate expansion, so
+// hygiene keeps them apart.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! map_fresh(
+    ($k:tt, [$(($x:ident $g:expr))+]) => {
+        $crate::map_fresh!(@apply $k, [$($x)+], $crate::zip_nested!($($g),+))
+    };
+    ($k:tt, [$($acc:tt)*] $g:expr $(, $rest:expr)*) => {
+        $crate::map_fresh!($k, [$($acc)* (x $g)] $($rest),*)
+    };
+    (@apply (tuple), [$($x:ident)+], $zipped:expr) => {
+        $crate::gen::map(
+            ::std::rc::Rc::new(|$crate::pat_nested!($($x),+)| ($($x,)+)),
+            $zipped,
+        )
+    };
+    (@apply (map $f:expr), [$($x:ident)+], $zipped:expr) => {{
+        let f = $f;
+        $crate::gen::map(
+            ::std::rc::Rc::new(move |$crate::pat_nested!($($x),+)| f($($x),+)),
+            $zipped,
+        )
+    }};
+);
+
+/// Apply a function of any arity to the values of as many generators, e.g.
+/// `map!(|name, age| Person { name, age }, gen::string(..), gen::u8(..))`. Like `map2`..`map8`,
+/// each argument shrinks independently.
+#[macro_export]
+macro_rules! map(
+    ($f:expr, $($g:expr),+ $(,)*) => {
+        $crate::map_fresh!((map $f), [] $($g),+)
+    };
+);
+
+/// Generate a tuple with one component per generator, like `zip3`..`zip12` but of any arity.
+///
+/// ```
+/// # #[macro_use] extern crate hedgehog;
+/// # use hedgehog::{gen, range};
+/// # fn main() {
+/// let point = zip!(gen::i32(range::linear(-10, 10)), gen::i32(range::linear(-10, 10)));
+/// let label = map!(|(x, y), c| format!("{}{}:{}", c, x, y), point, gen::upper());
+/// # let _ = label;
+/// # }
+/// ```
+#[macro_export]
+macro_rules! zip(
+    ($($g:expr),+ $(,)*) => {
+        $crate::map_fresh!((tuple), [] $($g),+)
+    };
+);
+
+macro_rules! tuple_n(
+    ($name:ident, $($x:ident),+) => {
+        pub fn $name<'a, A>(g: Gen<'a, A>) -> Gen<'a, ($(replace_ty!($x, A),)+)>
+        where
+            A: Clone + 'a,
+        {
+            map(
+                Rc::new(|pat_nested!($($x),+)| ($($x,)+)),
+                zip_nested!($(replace_expr!($x, g.clone())),+),
+            )
+        }
+    };
+);
+
+tuple_n!(tuple3, x1, x2, x3);
+tuple_n!(tuple4, x1, x2, x3, x4);
+tuple_n!(tuple5, x1, x2, x3, x4, x5);
+tuple_n!(tuple6, x1, x2, x3, x4, x5, x6);
+tuple_n!(tuple7, x1, x2, x3, x4, x5, x6, x7);
+tuple_n!(tuple8, x1, x2, x3, x4, x5, x6, x7, x8);
+tuple_n!(tuple9, x1, x2, x3, x4, x5, x6, x7, x8, x9);
+tuple_n!(tuple10, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10);
+tuple_n!(tuple11, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11);
+tuple_n!(tuple12, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12);
+
 pub fn no_shrink<'a, A>(g: Gen<'a, A>) -> Gen<'a, A>
 where
     A: Clone + 'a,
@@ -195,7 +405,7 @@ where
 
 pub fn integral<'a, A>(range: Range<'a, A>) -> Gen<'a, A>
 where
-    A: Copy + ToPrimitive + FromPrimitive + Integer + Clone + 'a,
+    A: Copy + FromPrimitive + Integer + SampleUniform + Clone + 'a,
 {
     let range1 = range.clone();
     create(
@@ -216,13 +426,13 @@ where
         where
             X: Clone,
         {
-            random::run(seed.clone(), size.clone(), random.clone())
+            random::run(seed.clone(), size, random.clone())
         }
         let t1 = run(seed1, size, m.clone());
         let k1 = k.clone();
         tree::bind(
             t1,
-            Rc::new(move |x: A| run(seed2.clone(), size.clone(), k1(x.clone()))),
+            Rc::new(move |x: A| run(seed2.clone(), size, k1(x.clone()))),
         )
     })
 }
@@ -248,6 +458,66 @@ where
     constant(a)
 }
 
+// Shared by `gen!` and `property!`. Every value bound so far is kept in a hidden variable that is
+// cloned into each nested closure, and its pattern is matched again there, so that the closures
+// stay `Fn` however many times the continuation is rerun while shrinking.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! do_notation(
+    ($bind:path, $pure:path, [$($q:tt $w:ident)*] yield $e:expr $(;)*) => {
+        $pure($e)
+    };
+    ($bind:path, $pure:path, [$($q:tt $w:ident)*] let $p:tt <- $m:expr; $($rest:tt)+) => {
+        $bind($m, move |__v| {
+            $(
+                #[allow(unused_variables, clippy::let_unit_value)]
+                let $w = $w.clone();
+            )*
+            $(
+                #[allow(unused_variables, clippy::let_unit_value)]
+                let $q = $w.clone();
+            )*
+            #[allow(unused_variables, clippy::let_unit_value)]
+            let $p = __v.clone();
+            $crate::do_notation!($bind, $pure, [$($q $w)* $p __v] $($rest)+)
+        })
+    };
+    ($bind:path, $pure:path, [$($q:tt $w:ident)*] let $p:tt = $e:expr; $($rest:tt)+) => {{
+        let __v = $e;
+        #[allow(unused_variables, clippy::let_unit_value)]
+        let $p = __v.clone();
+        $crate::do_notation!($bind, $pure, [$($q $w)* $p __v] $($rest)+)
+    }};
+    ($bind:path, $pure:path, [$($q:tt $w:ident)*] $m:expr $(;)*) => {
+        $m
+    };
+);
+
+/// Do-notation for generators, expanding into `bind` and `constant`.
+///
+/// `let p <- g;` binds the value generated by `g`, `let p = e;` binds a plain value, and the block
+/// ends with either `yield e` or a final generator. Patterns are single tokens, i.e. names, `_` or
+/// tuples, and bound values are cloned into later steps as needed.
+///
+/// ```
+/// # #[macro_use] extern crate hedgehog;
+/// # use hedgehog::{gen, range};
+/// # fn main() {
+/// let g = gen! {
+///     let n <- gen::usize(range::constant(1, 10));
+///     let xs <- gen::vec(range::singleton(n), gen::bool());
+///     yield (n, xs)
+/// };
+/// # let _ = g;
+/// # }
+/// ```
+#[macro_export]
+macro_rules! gen(
+    ($($body:tt)+) => {
+        $crate::do_notation!($crate::gen::Gen::bind, $crate::gen::constant, [] $($body)+)
+    };
+);
+
 pub fn item<'a, I, A>(xs0: I) -> Gen<'a, A>
 where
     A: Clone + 'a,
@@ -273,12 +543,12 @@ where
 
     let pick = move |mut n, ys: Vec<(isize, Gen<'a, A>)>| {
         ys.into_iter()
-            .fold(None, |acc, (k, y)| {
-                if n <= k {
-                    Some(y)
-                } else {
-                    n = n - k;
-                    acc
+            .fold(None, |acc, (k, y)| match acc {
+                Some(_) => acc,
+                None if n <= k => Some(y),
+                None => {
+                    n -= k;
+                    None
                 }
             })
             .expect("gen::frequency: 'xs' must have at least one element")
@@ -338,25 +608,23 @@ where
         B: Clone + 'b,
         G: Fn(B) -> bool + 'b,
     {
-        let p2 = p1.clone();
-        if k == Size(0) {
+        if n == Size(0) {
             random::constant(None)
         } else {
             let r0 = r1.clone();
-            let r1 = random::resize(Size(2 * k.0 + n.0), r0.clone());
-            let r2 = r1.clone();
-            let p3 = p2.clone();
+            let r2 = random::resize(Size(2 * k.0 + n.0), r0.clone());
+            let p2 = p1.clone();
             let f = Rc::new(move |x: Tree<'b, B>| {
-                if p3(tree::outcome(x.clone())) {
-                    random::constant(Some(tree::filter(p3.clone(), x)))
+                if p2(tree::outcome(x.clone())) {
+                    random::constant(Some(tree::filter(p2.clone(), x)))
                 } else {
                     let size1 = Size(k.0 + 1);
-                    try_n(p3.clone(), r1.clone(), size1, Size(n.0 - 1))
+                    try_n(p2.clone(), r0.clone(), size1, Size(n.0 - 1))
                 }
             });
             random::bind(r2, f)
         }
-    };
+    }
     let p1 = p.clone();
     random::sized(Rc::new(move |s: Size| {
         let clamp_size = Size(s.0.max(1));
@@ -437,14 +705,26 @@ where
     }))
 }
 
-pub fn char<'a>(lo: char, hi: char) -> Gen<'a, char> {
-    // Just pretend we can unwrap for now since that's what other langs do.
+/// Generate a character in `range`, shrinking towards its origin. Surrogates are skipped, see
+/// `range::char_index`.
+pub fn char_in<'a>(range: Range<'a, char>) -> Gen<'a, char> {
     map(
-        Rc::new(move |x| unsafe { std::char::from_u32_unchecked(x) }),
-        integral(range::constant(lo as u32, hi as u32)),
+        Rc::new(range::char_from_index),
+        integral(Range::map(range::char_index, range)),
     )
 }
 
+/// Generate a character between `lo` and `hi`, shrinking towards `lo`.
+pub fn char<'a>(lo: char, hi: char) -> Gen<'a, char> {
+    char_in(range::constant(lo, hi))
+}
+
+/// Generate a character between `lo` and `hi`, shrinking towards `origin`, e.g. `'a'` or `' '`.
+pub fn char_from<'a>(origin: char, lo: char, hi: char) -> Gen<'a, char> {
+    char_in(range::constant_from(origin, lo, hi))
+}
+
+/// Every `char`, including the noncharacters `U+FFFE` and `U+FFFF`.
 pub fn unicode_all<'a>() -> Gen<'a, char> {
     char('\0', std::char::MAX)
 }
@@ -462,23 +742,92 @@ pub fn upper<'a>() -> Gen<'a, char> {
 }
 
 pub fn latin1<'a>() -> Gen<'a, char> {
-    char('\u{000}', '\u{255}')
+    char('\0', '\u{ff}')
 }
 
+/// Every `char` except the noncharacters `U+FFFE` and `U+FFFF`, weighted by the size of each
+/// block like Haskell hedgehog's `unicode`.
 pub fn unicode<'a>() -> Gen<'a, char> {
-    let unicode_all_opt = move |lo, hi| {
-        map(
-            Rc::new(move |x| std::char::from_u32(x)),
-            integral(range::constant(lo as u32, hi as u32)),
-        )
-    };
+    frequency(
+        vec![
+            (0xd800, char('\0', '\u{d7ff}')),
+            (0x1ffe, char('\u{e000}', '\u{fffd}')),
+            (0x100000, char('\u{10000}', std::char::MAX)),
+        ]
+        .into_iter(),
+    )
+}
+
+// A range of the table is picked uniformly and then a character within it. Each range counts
+// the same however long it is, so a character in a one-character range comes up far more often
+// than one in a long range such as the CJK ideographs, and scripts with many short ranges come up
+// more often than scripts with a few long ones.
+fn one_of_ranges<'a>(ranges: &'static [(char, char)]) -> Gen<'a, char> {
+    choice(ranges.iter().map(|&(lo, hi)| char(lo, hi)))
+}
 
-    let unicode_all_opt_gen = unicode_all_opt('\0', std::char::MAX);
-    let reject_none = Rc::new(move |x: Option<char>| x.is_some());
+/// A letter from any script, see `unicode::LETTER`.
+pub fn letter<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::LETTER)
+}
 
-    map(
-        Rc::new(move |x: Option<char>| x.unwrap()),
-        filter(reject_none, unicode_all_opt_gen),
+/// A combining mark, see `unicode::MARK`.
+pub fn mark<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::MARK)
+}
+
+/// A digit, numeral or other number from any script, see `unicode::NUMBER`.
+pub fn number<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::NUMBER)
+}
+
+/// A punctuation mark from any script, see `unicode::PUNCTUATION`.
+pub fn punctuation<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::PUNCTUATION)
+}
+
+/// A symbol, emoji included, see `unicode::SYMBOL`.
+pub fn symbol<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::SYMBOL)
+}
+
+/// A space, line separator or paragraph separator, see `unicode::SEPARATOR`.
+pub fn separator<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::SEPARATOR)
+}
+
+/// A control character, see `unicode::CONTROL`.
+pub fn control<'a>() -> Gen<'a, char> {
+    one_of_ranges(unicode::CONTROL)
+}
+
+/// A character from `script`, see `Script::ranges`.
+pub fn script<'a>(script: Script) -> Gen<'a, char> {
+    one_of_ranges(script.ranges())
+}
+
+/// A character from the block called `name`, which is matched loosely, see `unicode::block`.
+pub fn block<'a>(name: &str) -> Gen<'a, char> {
+    match unicode::block(name) {
+        Some((lo, hi)) => char(lo, hi),
+        None => panic!("gen::block: there is no block called {:?}", name),
+    }
+}
+
+/// Characters that tend to break text handling: see `unicode::INTERESTING`, plus combining marks,
+/// format characters and characters that change under normalization. Plain ASCII letters are
+/// mixed in so that strings built from this have something for marks and joiners to attach to.
+pub fn unicode_interesting<'a>() -> Gen<'a, char> {
+    frequency(
+        vec![
+            (3, alpha()),
+            (4, one_of_ranges(unicode::INTERESTING)),
+            (2, mark()),
+            (1, one_of_ranges(unicode::FORMAT)),
+            (1, one_of_ranges(unicode::DECOMPOSABLE)),
+            (1, one_of_ranges(unicode::COMPATIBILITY)),
+        ]
+        .into_iter(),
     )
 }
 
@@ -512,7 +861,7 @@ where
                 let r: Random<'a, Vec<Tree<'a, A>>> = random::replicate(k, to_random(g.clone()));
                 let h = Rc::new(move |r| {
                     let range = range.clone();
-                    let r0: Tree<'a, Vec<A>> = shrink::sequence_list(r);
+                    let r0: Tree<'a, Vec<A>> = tree::interleave(r);
                     let f = Rc::new(move |xs| {
                         let range = range.clone();
                         at_least(range::lower_bound(size, range), xs)
@@ -525,8 +874,56 @@ where
     })))
 }
 
-/// Feeding this function anything other than `unicode` may result in errors as this checks for
-/// valid UTF-8 on construction (per Rust's `String` type).
+// The other sequence types are built from a generated `Vec`, so they share its size range and
+// its shrinking.
+pub fn vec_deque<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, VecDeque<A>>
+where
+    A: Clone + 'a,
+{
+    map(Rc::new(VecDeque::from), vec(range, g))
+}
+
+pub fn linked_list<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, LinkedList<A>>
+where
+    A: Clone + 'a,
+{
+    map(
+        Rc::new(|xs: Vec<A>| xs.into_iter().collect()),
+        vec(range, g),
+    )
+}
+
+pub fn binary_heap<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, BinaryHeap<A>>
+where
+    A: Clone + Ord + 'a,
+{
+    map(Rc::new(BinaryHeap::from), vec(range, g))
+}
+
+pub fn boxed_slice<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, Box<[A]>>
+where
+    A: Clone + 'a,
+{
+    map(Rc::new(Vec::into_boxed_slice), vec(range, g))
+}
+
+/// Generate an array of exactly `N` elements. Only the elements shrink, see
+/// `shrink::sequence_elems`, since the length is part of the type.
+pub fn array<'a, A, const N: usize>(g: Gen<'a, A>) -> Gen<'a, [A; N]>
+where
+    A: Clone + 'a,
+{
+    let r = random::replicate(N, to_random(g));
+    let elems = random::map(Rc::new(shrink::sequence_elems), r);
+    map(
+        Rc::new(|xs: Vec<A>| match xs.try_into() {
+            Ok(xs) => xs,
+            Err(_) => panic!("internal error: expected {} elements", N),
+        }),
+        from_random(elems),
+    )
+}
+
 pub fn string<'a>(range: Range<'a, usize>, g: Gen<'a, char>) -> Gen<'a, String> {
     map(
         Rc::new(move |cs: Vec<char>| {
@@ -538,6 +935,316 @@ pub fn string<'a>(range: Range<'a, usize>, g: Gen<'a, char>) -> Gen<'a, String>
     )
 }
 
+// How many repetitions past the minimum `*`, `+` and `{n,}` may produce at the largest size.
+const REGEX_REPEAT_LIMIT: usize = 10;
+
+/// Generate strings matching `pattern`, in the syntax described in the `regex` module. The whole
+/// string matches, as if the pattern were surrounded by `^` and `$`, so anchors generate nothing.
+/// Shrinking stays within the language: repetitions are removed down to their minimum,
+/// alternation shrinks towards earlier alternatives and classes towards their smallest member.
+///
+/// Panics if `pattern` does not parse, see `regex::parse`.
+pub fn from_regex<'a>(pattern: &str) -> Gen<'a, String> {
+    match regex::parse(pattern) {
+        Ok(r) => regex_gen(&r),
+        Err(e) => panic!("gen::from_regex: {}", e),
+    }
+}
+
+fn regex_gen<'a>(r: &Regex) -> Gen<'a, String> {
+    match r {
+        Regex::Empty => constant(String::new()),
+        Regex::Class(ranges) => map(Rc::new(|c: char| c.to_string()), regex_class(ranges)),
+        Regex::Concat(rs) => regex_concat(rs.iter().map(regex_gen).collect()),
+        Regex::Alternate(rs) => choice(rs.iter().map(regex_gen)),
+        Regex::Repeat(r, min, max) => {
+            let max = max.unwrap_or(min.saturating_add(REGEX_REPEAT_LIMIT));
+            map(
+                Rc::new(|parts: Vec<String>| parts.concat()),
+                vec(range::linear(*min, max), regex_gen(r)),
+            )
+        }
+    }
+}
+
+// Printable ASCII is preferred when a class has more than that, e.g. `.` or `[^a]`, as it is the
+// more readable and simpler to shrink to.
+fn regex_class<'a>(ranges: &[(char, char)]) -> Gen<'a, char> {
+    fn pick<'a>(ranges: &[(char, char)]) -> Gen<'a, char> {
+        let gs: Vec<Gen<'a, char>> = ranges.iter().map(|&(lo, hi)| char(lo, hi)).collect();
+        choice(gs.into_iter())
+    }
+    let ascii: Vec<(char, char)> = ranges
+        .iter()
+        .map(|&(lo, hi)| (lo.max(' '), hi.min('~')))
+        .filter(|&(lo, hi)| lo <= hi)
+        .collect();
+    if ascii.is_empty() || ascii == ranges {
+        pick(ranges)
+    } else {
+        frequency(vec![(3, pick(&ascii)), (1, pick(ranges))].into_iter())
+    }
+}
+
+// Each part shrinks on its own and none can be dropped, see `shrink::sequence_elems`.
+fn regex_concat<'a>(gs: Vec<Gen<'a, String>>) -> Gen<'a, String> {
+    let rs: Vec<Random<'a, Tree<'a, String>>> = gs.into_iter().map(to_random).collect();
+    from_random(Rc::new(move |seed0, size| {
+        let mut seed = seed0;
+        let mut ts = Vec::with_capacity(rs.len());
+        for r in rs.iter() {
+            let (seed1, seed2) = seed::split(seed);
+            ts.push(random::run(seed1, size, r.clone()));
+            seed = seed2;
+        }
+        tree::map(
+            Rc::new(|parts: Vec<String>| parts.concat()),
+            shrink::sequence_elems(ts),
+        )
+    }))
+}
+
+// The keys seen so far while generating a collection without duplicates.
+trait Keys<K>: Default {
+    fn insert_key(&mut self, k: K) -> bool;
+}
+
+impl<K> Keys<K> for HashSet<K>
+where
+    K: Eq + Hash,
+{
+    fn insert_key(&mut self, k: K) -> bool {
+        self.insert(k)
+    }
+}
+
+impl<K> Keys<K> for BTreeSet<K>
+where
+    K: Ord,
+{
+    fn insert_key(&mut self, k: K) -> bool {
+        self.insert(k)
+    }
+}
+
+fn all_unique<K, S, A, F>(key: &F, xs: &[A]) -> bool
+where
+    S: Keys<K>,
+    F: Fn(&A) -> K,
+{
+    let mut keys = S::default();
+    xs.iter().all(|x| keys.insert_key(key(x)))
+}
+
+// How many draws in a row may produce a key that is already taken before settling for the
+// elements found so far, and how many times to start over when those are too few.
+const UNIQUE_ATTEMPTS: usize = 100;
+
+// Like `vec`, but no two elements share a key. Elements that collide with an earlier one are
+// drawn again, and shrinks that would introduce a duplicate are dropped from the tree. The result
+// always has the length picked from the range: when `UNIQUE_ATTEMPTS` draws in a row collide the
+// attempt is discarded, and generation starts over with a new length at a larger size, as
+// `filter` does, up to `UNIQUE_ATTEMPTS` times.
+fn unique_by<'a, A, K, S, F>(range: Range<'a, usize>, key: Rc<F>, g: Gen<'a, A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + 'a,
+    K: 'a,
+    S: Keys<K> + 'a,
+    F: Fn(&A) -> K + 'a,
+{
+    let r = to_random(g);
+    let range1 = range.clone();
+    let attempt: Random<'a, Option<Tree<'a, Vec<A>>>> = random::sized(Rc::new(move |size| {
+        let r = r.clone();
+        let key = key.clone();
+        let range = range.clone();
+        random::bind(
+            random::integral(range.clone()),
+            Rc::new(move |n: usize| {
+                let r = r.clone();
+                let key = key.clone();
+                let lo = range::lower_bound(size, range.clone());
+                let unique: Random<'a, Option<Tree<'a, Vec<A>>>> = Rc::new(move |seed0, size| {
+                    let mut seed = seed0;
+                    let mut keys = S::default();
+                    let mut ts = Vec::with_capacity(n);
+                    let mut misses = 0;
+                    while ts.len() < n && misses < UNIQUE_ATTEMPTS {
+                        let (seed1, seed2) = seed::split(seed);
+                        seed = seed2;
+                        let t = random::run(seed1, size, r.clone());
+                        if keys.insert_key(key(&t.value())) {
+                            ts.push(t);
+                            misses = 0;
+                        } else {
+                            misses += 1;
+                        }
+                    }
+                    if ts.len() < n {
+                        return None;
+                    }
+                    let key = key.clone();
+                    let ok = Rc::new(move |xs: Vec<A>| {
+                        xs.len() >= lo && all_unique::<K, S, A, F>(&*key, &xs)
+                    });
+                    Some(tree::filter(ok, tree::interleave(ts)))
+                });
+                unique
+            }),
+        )
+    }));
+    from_random(Rc::new(move |seed0, size0| {
+        let mut seed = seed0;
+        let mut size = size0;
+        for _ in 0..UNIQUE_ATTEMPTS {
+            let (seed1, seed2) = seed::split(seed);
+            if let Some(t) = random::run(seed1, size, attempt.clone()) {
+                return t;
+            }
+            seed = seed2;
+            size = Size(size.0 + 1);
+        }
+        panic!(
+            "gen: could not generate at least {} elements with unique keys",
+            range::lower_bound(size0, range1.clone())
+        )
+    }))
+}
+
+/// Generate a set with a size in `range`. Duplicate elements are drawn again, and when `g` cannot
+/// come up with enough distinct values another size is picked. Panics if even that keeps failing,
+/// e.g. when `g` has fewer distinct values than the lower bound of `range`.
+pub fn hash_set<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, HashSet<A>>
+where
+    A: Clone + Eq + Hash + 'a,
+{
+    let xs = unique_by::<_, _, HashSet<A>, _>(range, Rc::new(|x: &A| x.clone()), g);
+    map(Rc::new(|xs: Vec<A>| xs.into_iter().collect()), xs)
+}
+
+/// Like `hash_set`, but for ordered elements.
+pub fn btree_set<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, BTreeSet<A>>
+where
+    A: Clone + Ord + 'a,
+{
+    let xs = unique_by::<_, _, BTreeSet<A>, _>(range, Rc::new(|x: &A| x.clone()), g);
+    map(Rc::new(|xs: Vec<A>| xs.into_iter().collect()), xs)
+}
+
+/// Generate a map with a size in `range` from key-value pairs. Pairs whose key is already taken
+/// are drawn again, and another size is picked when the keys run out, as for `hash_set`.
+pub fn hash_map<'a, K, V>(range: Range<'a, usize>, g: Gen<'a, (K, V)>) -> Gen<'a, HashMap<K, V>>
+where
+    K: Clone + Eq + Hash + 'a,
+    V: Clone + 'a,
+{
+    let kvs = unique_by::<_, _, HashSet<K>, _>(range, Rc::new(|kv: &(K, V)| kv.0.clone()), g);
+    map(Rc::new(|kvs: Vec<(K, V)>| kvs.into_iter().collect()), kvs)
+}
+
+/// Like `hash_map`, but for ordered keys.
+pub fn btree_map<'a, K, V>(range: Range<'a, usize>, g: Gen<'a, (K, V)>) -> Gen<'a, BTreeMap<K, V>>
+where
+    K: Clone + Ord + 'a,
+    V: Clone + 'a,
+{
+    let kvs = unique_by::<_, _, BTreeSet<K>, _>(range, Rc::new(|kv: &(K, V)| kv.0.clone()), g);
+    map(Rc::new(|kvs: Vec<(K, V)>| kvs.into_iter().collect()), kvs)
+}
+
+/// Generate a vector with a size in `range` and no duplicate elements. Duplicates are drawn
+/// again, as with `hash_set`, but the order of generation is kept.
+pub fn unique_vec<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + Eq + Hash + 'a,
+{
+    unique_by::<_, _, HashSet<A>, _>(range, Rc::new(|x: &A| x.clone()), g)
+}
+
+/// Like `unique_vec`, but two elements count as duplicates when `key` maps them to the same value.
+pub fn unique_vec_by<'a, A, K, F>(
+    range: Range<'a, usize>,
+    key: Rc<F>,
+    g: Gen<'a, A>,
+) -> Gen<'a, Vec<A>>
+where
+    A: Clone + 'a,
+    K: Eq + Hash + 'a,
+    F: Fn(&A) -> K + 'a,
+{
+    unique_by::<_, _, HashSet<K>, _>(range, key, g)
+}
+
+/// Generate a vector with a size in `range` whose elements are in ascending order.
+pub fn sorted_vec<'a, A>(range: Range<'a, usize>, g: Gen<'a, A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + Ord + 'a,
+{
+    map(
+        Rc::new(|mut xs: Vec<A>| {
+            xs.sort();
+            xs
+        }),
+        vec(range, g),
+    )
+}
+
+/// Generate a subsequence of `xs`, keeping the order of the elements it picks. Shrinks by
+/// dropping elements.
+pub fn subsequence<'a, A>(xs: Vec<A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + 'a,
+{
+    let keep = random::replicate(xs.len(), to_random(bool()));
+    from_random(random::map(
+        Rc::new(move |keep: Vec<Tree<'a, bool>>| {
+            let ts = xs
+                .iter()
+                .zip(keep)
+                .filter(|(_, k)| k.value())
+                .map(|(x, _)| Tree::singleton(x.clone()))
+                .collect();
+            tree::interleave(ts)
+        }),
+        keep,
+    ))
+}
+
+/// Generate a permutation of `xs`. Each position picks one of the elements left over, and the
+/// picks shrink towards the first of them, so shrinking moves towards the original order.
+pub fn shuffle<'a, A>(xs: Vec<A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + 'a,
+{
+    let n = xs.len();
+    let picks: Random<'a, Tree<'a, Vec<usize>>> = Rc::new(move |seed0, size| {
+        let mut seed = seed0;
+        let mut ts = Vec::with_capacity(n);
+        for i in 0..n {
+            let (seed1, seed2) = seed::split(seed);
+            seed = seed2;
+            let pick = integral(range::constant(0, n - 1 - i));
+            ts.push(random::run(seed1, size, to_random(pick)));
+        }
+        shrink::sequence_elems(ts)
+    });
+    map(
+        Rc::new(move |picks: Vec<usize>| {
+            let mut rest = xs.clone();
+            picks.into_iter().map(|i| rest.remove(i)).collect()
+        }),
+        from_random(picks),
+    )
+}
+
+/// The same as `shuffle`.
+pub fn permutation<'a, A>(xs: Vec<A>) -> Gen<'a, Vec<A>>
+where
+    A: Clone + 'a,
+{
+    shuffle(xs)
+}
+
 pub fn bool<'a>() -> Gen<'a, bool> {
     item(vec![false, true].into_iter())
 }
@@ -575,6 +1282,14 @@ pub fn i64<'a>(range: Range<'a, i64>) -> Gen<'a, i64> {
     integral(range)
 }
 
+pub fn u128<'a>(range: Range<'a, u128>) -> Gen<'a, u128> {
+    integral(range)
+}
+
+pub fn i128<'a>(range: Range<'a, i128>) -> Gen<'a, i128> {
+    integral(range)
+}
+
 pub fn usize<'a>(range: Range<'a, usize>) -> Gen<'a, usize> {
     integral(range)
 }
@@ -583,6 +1298,44 @@ pub fn isize<'a>(range: Range<'a, isize>) -> Gen<'a, isize> {
     integral(range)
 }
 
+/// Generate an integer of any size in `range`, shrinking towards its origin like `integral`.
+pub fn big_int<'a>(range: Range<'a, BigInt>) -> Gen<'a, BigInt> {
+    let range1 = range.clone();
+    create(
+        Rc::new(move |x| shrink::towards(range::origin(range1.clone()), x)),
+        random::big_int(range),
+    )
+}
+
+pub fn big_uint<'a>(range: Range<'a, BigUint>) -> Gen<'a, BigUint> {
+    let range = Range::map(BigInt::from, range);
+    map(Rc::new(|x: BigInt| x.to_biguint().unwrap()), big_int(range))
+}
+
+/// Generate a rational number in `range`.
+///
+/// The denominator is a multiple of the origin's denominator, so that the origin can always be
+/// hit, and the bounds are rounded inwards to it. The multiple grows with the size, starting from
+/// 1. Shrinking prefers simpler denominators, see `shrink::towards_ratio`.
+pub fn ratio<'a>(range: Range<'a, BigRational>) -> Gen<'a, BigRational> {
+    let range1 = range.clone();
+    let random: Random<'a, BigRational> = Rc::new(move |seed, size| {
+        let (lo, hi) = range::bounds(size, range.clone());
+        let z = range::origin(range.clone());
+        let (seed1, seed2) = seed::split(seed);
+        let k = random::run(seed1, size, random::integral(range::linear(1u32, 1000)));
+        let d = BigRational::from_integer(z.denom() * BigInt::from(k));
+        let lo = (&lo * &d).ceil().to_integer();
+        let hi = (&hi * &d).floor().to_integer();
+        let (n, _) = seed::next_big_int(&lo, &hi, seed2);
+        BigRational::new(n, d.to_integer())
+    });
+    create(
+        Rc::new(move |x| shrink::towards_ratio(range::origin(range1.clone()), x)),
+        random,
+    )
+}
+
 pub fn f64<'a>(range: Range<'a, f64>) -> Gen<'a, f64> {
     let r1 = range.clone();
     create(
@@ -599,6 +1352,237 @@ pub fn f32<'a>(range: Range<'a, f32>) -> Gen<'a, f32> {
     )
 }
 
+/// Special floating-point values that `f64_with`, `f32_with`, `f64_any` and `f32_any` mix in with
+/// the regular draws. Start from `specials::default()` or `specials::none()` and adjust it with the
+/// `specials::with_*` functions. The generators panic if `frequency` is not between 0 and 100.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Specials {
+    /// `NaN`.
+    pub nan: bool,
+    /// Positive and negative infinity.
+    pub infinities: bool,
+    /// `-0.0`.
+    pub negative_zero: bool,
+    /// The smallest and largest subnormals, and the smallest normal value.
+    pub subnormals: bool,
+    /// `MAX`, `MIN` and their neighbours.
+    pub extremes: bool,
+    /// Out of every 100 draws, roughly how many are special values. Must be between 0 and 100.
+    pub frequency: isize,
+}
+
+impl Default for Specials {
+    fn default() -> Self {
+        Specials {
+            nan: true,
+            infinities: true,
+            negative_zero: true,
+            subnormals: true,
+            extremes: true,
+            frequency: 10,
+        }
+    }
+}
+
+pub mod specials {
+    use super::Specials;
+
+    pub fn default() -> Specials {
+        Specials::default()
+    }
+
+    /// No special values at all, which makes `f64_with` behave like `f64`.
+    pub fn none() -> Specials {
+        Specials {
+            nan: false,
+            infinities: false,
+            negative_zero: false,
+            subnormals: false,
+            extremes: false,
+            ..Specials::default()
+        }
+    }
+
+    pub fn with_nan(nan: bool, specials: Specials) -> Specials {
+        Specials { nan, ..specials }
+    }
+
+    pub fn with_infinities(infinities: bool, specials: Specials) -> Specials {
+        Specials {
+            infinities,
+            ..specials
+        }
+    }
+
+    pub fn with_negative_zero(negative_zero: bool, specials: Specials) -> Specials {
+        Specials {
+            negative_zero,
+            ..specials
+        }
+    }
+
+    pub fn with_subnormals(subnormals: bool, specials: Specials) -> Specials {
+        Specials {
+            subnormals,
+            ..specials
+        }
+    }
+
+    pub fn with_extremes(extremes: bool, specials: Specials) -> Specials {
+        Specials {
+            extremes,
+            ..specials
+        }
+    }
+
+    /// Panics if `frequency` is not between 0 and 100.
+    pub fn with_frequency(frequency: isize, specials: Specials) -> Specials {
+        super::check_frequency(frequency);
+        Specials {
+            frequency,
+            ..specials
+        }
+    }
+}
+
+fn check_frequency(frequency: isize) {
+    assert!(
+        (0..=100).contains(&frequency),
+        "gen::specials: frequency {} is not between 0 and 100",
+        frequency
+    );
+}
+
+fn special_f64s(specials: Specials) -> Vec<f64> {
+    let mut xs = vec![];
+    if specials.nan {
+        xs.push(f64::NAN);
+    }
+    if specials.infinities {
+        xs.extend([f64::INFINITY, f64::NEG_INFINITY]);
+    }
+    if specials.negative_zero {
+        xs.push(-0.0);
+    }
+    if specials.subnormals {
+        let tiny = f64::from_bits(1);
+        let largest = f64::from_bits(0x000f_ffff_ffff_ffff);
+        xs.extend([tiny, -tiny, largest, -largest, f64::MIN_POSITIVE]);
+    }
+    if specials.extremes {
+        let below_max = f64::from_bits(f64::MAX.to_bits() - 1);
+        xs.extend([f64::MAX, f64::MIN, below_max, -below_max]);
+    }
+    xs
+}
+
+fn special_f32s(specials: Specials) -> Vec<f32> {
+    let mut xs = vec![];
+    if specials.nan {
+        xs.push(f32::NAN);
+    }
+    if specials.infinities {
+        xs.extend([f32::INFINITY, f32::NEG_INFINITY]);
+    }
+    if specials.negative_zero {
+        xs.push(-0.0);
+    }
+    if specials.subnormals {
+        let tiny = f32::from_bits(1);
+        let largest = f32::from_bits(0x007f_ffff);
+        xs.extend([tiny, -tiny, largest, -largest, f32::MIN_POSITIVE]);
+    }
+    if specials.extremes {
+        let below_max = f32::from_bits(f32::MAX.to_bits() - 1);
+        xs.extend([f32::MAX, f32::MIN, below_max, -below_max]);
+    }
+    xs
+}
+
+// Mixes `values` into `g`. A special value shrinks to the origin of `range` and to 1.0, when that
+// is within the bounds, and from there like any other float.
+fn with_specials<'a, A>(
+    specials: Specials,
+    values: Vec<A>,
+    range: Range<'a, A>,
+    g: Gen<'a, A>,
+) -> Gen<'a, A>
+where
+    A: Float + FromPrimitive + 'a,
+{
+    check_frequency(specials.frequency);
+    let weight = specials.frequency;
+    if values.is_empty() || weight == 0 {
+        return g;
+    }
+    let special = from_random(Rc::new(move |seed, size| {
+        let (lo, hi) = range::bounds(size, range.clone());
+        let origin = range::origin(range.clone());
+        let (i, _) = seed::next_integer(0, values.len() - 1, seed);
+        let mut simple = vec![origin];
+        if A::one() != origin && lo <= A::one() && A::one() <= hi {
+            simple.push(A::one());
+        }
+        let shrink = Rc::new(move |x| shrink::towards_float(origin, x));
+        let children = simple
+            .into_iter()
+            .map(|x| tree::unfold(Rc::new(|x| x), shrink.clone(), x))
+            .collect();
+        Tree::new(values[i], children)
+    }));
+    if weight == 100 {
+        special
+    } else {
+        frequency(vec![(100 - weight, g), (weight, special)].into_iter())
+    }
+}
+
+/// Like `f64`, but mixing in the special values enabled in `specials`. These are not bound by
+/// `range`.
+pub fn f64_with<'a>(specials: Specials, range: Range<'a, f64>) -> Gen<'a, f64> {
+    with_specials(specials, special_f64s(specials), range.clone(), f64(range))
+}
+
+pub fn f32_with<'a>(specials: Specials, range: Range<'a, f32>) -> Gen<'a, f32> {
+    with_specials(specials, special_f32s(specials), range.clone(), f32(range))
+}
+
+/// Generate any finite `f64`, drawn from all bit patterns so that every exponent is equally
+/// likely, mixing in the special values enabled in `specials`. Shrinks towards 0.0.
+pub fn f64_any<'a>(specials: Specials) -> Gen<'a, f64> {
+    let finite: Random<'a, f64> = Rc::new(|seed, _| {
+        let mut seed = seed;
+        loop {
+            let (bits, s) = seed::next_word64(seed);
+            seed = s;
+            let x = f64::from_bits(bits);
+            if x.is_finite() {
+                return x;
+            }
+        }
+    });
+    let g = create(Rc::new(|x| shrink::towards_float(0.0, x)), finite);
+    let range = range::constant_from(0.0, f64::MIN, f64::MAX);
+    with_specials(specials, special_f64s(specials), range, g)
+}
+
+pub fn f32_any<'a>(specials: Specials) -> Gen<'a, f32> {
+    let finite: Random<'a, f32> = Rc::new(|seed, _| {
+        let mut seed = seed;
+        loop {
+            let (bits, s) = seed::next_word32(seed);
+            seed = s;
+            let x = f32::from_bits(bits);
+            if x.is_finite() {
+                return x;
+            }
+        }
+    });
+    let g = create(Rc::new(|x| shrink::towards_float(0.0, x)), finite);
+    let range = range::constant_from(0.0, f32::MIN, f32::MAX);
+    with_specials(specials, special_f32s(specials), range, g)
+}
+
 // TODO:
 //   guid
 //   datetime
@@ -648,6 +1632,8 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::property::testing::{falsified, shrinks_to};
+    use crate::property::{journal, property, Status};
     use crate::range::Size;
     use crate::seed::global;
     use crate::shrink;
@@ -665,6 +1651,670 @@ mod test {
         );
     }
 
+    #[test]
+    fn frequency_respects_the_weights() {
+        let g = frequency(vec![(1, constant(0)), (1, constant(1)), (2, constant(2))].into_iter());
+        let xs = sample(Size(30), 4000, g);
+        let count = |k| xs.iter().filter(|&&x| x == k).count();
+        assert!((800..1200).contains(&count(0)));
+        assert!((800..1200).contains(&count(1)));
+        assert!((1800..2200).contains(&count(2)));
+    }
+
+    #[test]
+    fn filter_only_yields_matching_values() {
+        let g = filter(
+            Rc::new(|x: u32| x.is_multiple_of(2)),
+            u32(range::constant(0, 1_000_000)),
+        );
+        let xs = sample(Size(30), 100, g);
+        assert_eq!(xs.len(), 100);
+        assert!(xs.iter().all(|&x| x.is_multiple_of(2)));
+    }
+
+    #[test]
+    fn methods_agree_with_the_free_functions() {
+        let run = |g: Gen<'static, Vec<Option<(u8, bool)>>>| {
+            random::run(seed::from(42), Size(30), to_random(g))
+        };
+        let chained = u8(range::constant(0, 100))
+            .filter(|x| x % 2 == 0)
+            .map(|x| x / 2)
+            .zip(bool())
+            .option()
+            .vec(range::linear(0, 10));
+        let free = vec(
+            range::linear(0, 10),
+            option(zip(
+                map(
+                    Rc::new(|x| x / 2),
+                    filter(Rc::new(|x| x % 2 == 0), u8(range::constant(0, 100))),
+                ),
+                bool(),
+            )),
+        );
+        assert_eq!(run(chained), run(free));
+    }
+
+    #[test]
+    fn size_methods_control_the_size() {
+        let size = sized(Rc::new(|n: Size| constant(n.0)));
+        let xs = sample(Size(30), 10, size.clone().resize(7));
+        assert!(xs.iter().all(|&n| n == 7));
+        let ys = sample(Size(30), 10, size.scale(|n| n * 2));
+        assert!(ys.iter().all(|&n| n == 60));
+        let t = random::run(seed::from(1), Size(30), to_random(bool().no_shrink()));
+        assert!(t.children().is_empty());
+        let zs = sample(
+            Size(30),
+            10,
+            u8(range::constant(1, 5)).bind(|n| constant(n).vec(range::singleton(n as usize))),
+        );
+        assert!(zs
+            .iter()
+            .all(|xs| xs.iter().all(|&x| x as usize == xs.len())));
+    }
+
+    #[test]
+    fn do_notation_threads_values_through_binds() {
+        let g = gen! {
+            let n <- usize(range::constant(1, 10));
+            let (xs, flag) <- zip(vec(range::singleton(n), bool()), bool());
+            let m = n * 2;
+            yield (n, m, xs, flag)
+        };
+        let ys = sample(Size(30), 100, g);
+        assert!(ys.iter().all(|(n, m, xs, _)| xs.len() == *n && *m == 2 * n));
+    }
+
+    #[test]
+    fn do_notation_can_end_with_a_generator() {
+        let g = gen! {
+            let lo <- u8(range::constant(0, 100));
+            let _ <- bool();
+            u8(range::constant(lo, 200)).map(move |hi| (lo, hi))
+        };
+        let t = random::run(seed::from(3), Size(30), to_random(g));
+        let (lo, hi) = t.value();
+        assert!(lo <= hi);
+        // Shrinking reruns the continuations, which must keep the same invariant.
+        assert!(t.children().iter().all(|s| s.value().0 <= s.value().1));
+    }
+
+    #[test]
+    fn products_have_the_right_shape() {
+        let g = map3(
+            Rc::new(|x: u8, y: bool, z: char| (x, y, z)),
+            u8(range::constant(0, 10)),
+            bool(),
+            lower(),
+        );
+        assert!(sample(Size(30), 20, g)
+            .iter()
+            .all(|(x, _, z)| *x <= 10 && z.is_ascii_lowercase()));
+        let xs = sample(Size(30), 20, tuple12(u8(range::constant(5, 9))));
+        assert!(xs.iter().all(|t| t.0 >= 5 && t.11 <= 9));
+    }
+
+    #[test]
+    fn heterogeneous_zips_keep_each_type() {
+        let g = zip3(u8(range::constant(0, 10)), bool(), lower());
+        assert!(sample(Size(30), 20, g)
+            .iter()
+            .all(|(x, _, c)| *x <= 10 && c.is_ascii_lowercase()));
+        let g = zip12(
+            u8(range::singleton(1)),
+            u16(range::singleton(2)),
+            u32(range::singleton(3)),
+            u64(range::singleton(4)),
+            i8(range::singleton(5)),
+            i16(range::singleton(6)),
+            i32(range::singleton(7)),
+            i64(range::singleton(8)),
+            usize(range::singleton(9)),
+            isize(range::singleton(10)),
+            constant('k'),
+            constant("l"),
+        );
+        let xs = sample(Size(30), 1, g);
+        assert_eq!(xs, vec![(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 'k', "l")]);
+    }
+
+    #[test]
+    fn variadic_macros_accept_any_arity() {
+        let digit = || u8(range::constant(0, 9));
+        let g = map!(
+            |a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8, h: u8, i: u8| {
+                vec![a, b, c, d, e, f, g, h, i]
+            },
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+            digit(),
+        );
+        assert!(sample(Size(30), 20, g).iter().all(|xs| xs.len() == 9));
+        let g = zip!(digit(), bool(), lower(), constant(()));
+        assert!(sample(Size(30), 20, g).iter().all(|(x, _, _, ())| *x <= 9));
+        let g = zip!(digit());
+        assert!(sample(Size(30), 20, g).iter().all(|(x,)| *x <= 9));
+    }
+
+    #[test]
+    fn variadic_macros_shrink_each_argument() {
+        let g = zip!(
+            u8(range::constant(0, 100)),
+            u8(range::constant(0, 100)),
+            u8(range::constant(0, 100)),
+        );
+        let prop = |(a, b, c): (u8, u8, u8)| property::from_bool(a < b || c == 0);
+        let p = property::for_all_rendered(g, |x| format!("{:?}", x), &prop);
+        shrinks_to(p, vec!["(0, 0, 1)"]);
+    }
+
+    #[test]
+    fn zip_keeps_shrinking_the_first_component() {
+        // With `bind`, shrinking `y` regenerated nothing for `x`, leaving `x` at whatever `y`
+        // originally was.
+        let prop = |(x, y): (u8, u8)| property::from_bool(x < y);
+        shrinks_to(property::for_all_arbitrary(&prop), vec!["(0, 0)"]);
+    }
+
+    #[test]
+    fn sets_and_maps_reach_the_requested_size() {
+        let small = || u8(range::constant(0, 60));
+        let xs = sample(Size(30), 20, hash_set(range::singleton(50), small()));
+        assert!(xs.iter().all(|s| s.len() == 50));
+        let xs = sample(Size(30), 20, btree_set(range::constant(10, 20), small()));
+        assert!(xs.iter().all(|s| s.len() >= 10 && s.len() <= 20));
+        let xs = sample(
+            Size(30),
+            20,
+            hash_map(range::singleton(40), zip(small(), bool())),
+        );
+        assert!(xs.iter().all(|m| m.len() == 40));
+        let xs = sample(
+            Size(30),
+            20,
+            btree_map(range::singleton(50), zip(small(), bool())),
+        );
+        assert!(xs
+            .iter()
+            .all(|m| m.len() == 50 && m.keys().all(|&k| k <= 60)));
+    }
+
+    #[test]
+    fn sets_and_maps_reach_the_drawn_size_despite_collisions() {
+        // Ten distinct values, but most draws are 0.
+        let keys = || frequency(vec![(5, constant(0)), (1, u8(range::constant(1, 9)))].into_iter());
+        for n in 1..=10 {
+            let xs = sample(Size(30), 20, hash_set(range::singleton(n), keys()));
+            assert!(xs.iter().all(|s| s.len() == n));
+            let xs = sample(
+                Size(30),
+                20,
+                btree_map(range::singleton(n), zip(keys(), bool())),
+            );
+            assert!(xs.iter().all(|m| m.len() == n));
+        }
+    }
+
+    #[test]
+    fn sets_pick_another_size_when_the_values_run_out() {
+        let sets = sample(Size(30), 50, hash_set(range::constant(0, 10), bool()));
+        assert!(sets.iter().all(|s| s.len() <= 2));
+        assert!(sets.iter().any(|s| s.len() == 2));
+        let maps = sample(
+            Size(30),
+            50,
+            btree_map(range::constant(1, 10), zip(bool(), bool())),
+        );
+        assert!(maps.iter().all(|m| !m.is_empty() && m.len() <= 2));
+        let xs = sample(Size(30), 50, unique_vec(range::singleton(2), bool()));
+        assert!(xs.iter().all(|xs| xs.len() == 2 && xs[0] != xs[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "could not generate at least 3 elements with unique keys")]
+    fn sets_panic_when_the_lower_bound_cannot_be_met() {
+        sample(Size(30), 1, hash_set(range::singleton(3), bool()));
+    }
+
+    #[test]
+    fn sets_shrink_without_duplicates() {
+        let g = btree_set(range::linear(0, 20), u8(range::constant(0, 100)));
+        let prop = |s: BTreeSet<u8>| property::from_bool(s.len() < 5);
+        let p = property::for_all_rendered(g, |s| format!("{:?}", s), &prop);
+        shrinks_to(p, vec!["{0, 1, 2, 3, 4}"]);
+    }
+
+    #[test]
+    fn map_shrinks_keep_the_lower_bound() {
+        let g = hash_map(
+            range::constant(3, 10),
+            zip(u8(range::constant(0, 100)), u8(range::constant(0, 100))),
+        );
+        let t = random::run(seed::from(9), Size(30), to_random(g));
+        assert!(t.children().iter().all(|m| m.value().len() >= 3));
+    }
+
+    #[test]
+    fn vec_drops_runs_then_shrinks_elements() {
+        let g = vec(range::constant(0, 4), u8(range::constant(1, 100)));
+        let t = (0..)
+            .map(|n| random::run(seed::from(n), Size(30), to_random(g.clone())))
+            .find(|t| t.value().len() == 4)
+            .unwrap();
+        let xs = t.value();
+        let without = |drop: &[usize]| -> Vec<u8> {
+            (0..4)
+                .filter(|i| !drop.contains(i))
+                .map(|i| xs[i])
+                .collect()
+        };
+        let children: Vec<Vec<u8>> = t.children().iter().map(|c| c.value()).collect();
+        assert_eq!(
+            &children[..7],
+            &[
+                without(&[0, 1, 2, 3]),
+                without(&[0, 1]),
+                without(&[2, 3]),
+                without(&[0]),
+                without(&[1]),
+                without(&[2]),
+                without(&[3]),
+            ][..]
+        );
+        let mut shrunk_first = xs.clone();
+        shrunk_first[0] = 1;
+        assert_eq!(children[7], shrunk_first);
+    }
+
+    #[test]
+    fn sequences_share_the_vec_size_range() {
+        let g = || u8(range::constant(0, 9));
+        let xs = sample(Size(30), 20, vec_deque(range::constant(2, 5), g()));
+        assert!(xs.iter().all(|xs| xs.len() >= 2 && xs.len() <= 5));
+        let xs = sample(Size(30), 20, linked_list(range::constant(2, 5), g()));
+        assert!(xs.iter().all(|xs| xs.len() >= 2 && xs.len() <= 5));
+        let xs = sample(Size(30), 20, boxed_slice(range::constant(2, 5), g()));
+        assert!(xs.iter().all(|xs| xs.len() >= 2 && xs.len() <= 5));
+        let xs = sample(Size(30), 20, binary_heap(range::constant(2, 5), g()));
+        assert!(xs
+            .iter()
+            .all(|h| h.len() >= 2 && h.peek() == h.iter().max()));
+    }
+
+    #[test]
+    fn arrays_only_shrink_their_elements() {
+        let g = array::<u8, 4>(u8(range::constant(0, 100)));
+        let prop = |xs: [u8; 4]| property::from_bool(xs.iter().all(|&x| x < 50));
+        let t = random::run(seed::from(5), Size(30), to_random(g.clone()));
+        assert!(t.children().iter().all(|c| c.value().len() == 4));
+        let p = property::for_all_rendered(g, |xs| format!("{:?}", xs), &prop);
+        let journal = journal::to_vec(falsified(property::report(p)).journal);
+        assert!(
+            journal == vec!["[50, 0, 0, 0]"]
+                || journal == vec!["[0, 50, 0, 0]"]
+                || journal == vec!["[0, 0, 50, 0]"]
+                || journal == vec!["[0, 0, 0, 50]"],
+            "{:?}",
+            journal
+        );
+    }
+
+    #[test]
+    fn unique_and_sorted_vecs_hold_their_invariants() {
+        let xs = sample(
+            Size(30),
+            20,
+            unique_vec(range::constant(0, 20), u8(range::constant(0, 30))),
+        );
+        assert!(xs
+            .iter()
+            .all(|xs| xs.iter().collect::<HashSet<_>>().len() == xs.len()));
+        let g = unique_vec_by(
+            range::constant(3, 3),
+            Rc::new(|x: &u8| x % 4),
+            u8(range::constant(0, 99)),
+        );
+        let xs = sample(Size(30), 20, g);
+        assert!(xs
+            .iter()
+            .all(|xs| xs.iter().map(|x| x % 4).collect::<HashSet<_>>().len() == 3));
+        let xs = sample(
+            Size(30),
+            20,
+            sorted_vec(range::constant(0, 20), u8(range::constant(0, 99))),
+        );
+        assert!(xs.iter().all(|xs| xs.windows(2).all(|w| w[0] <= w[1])));
+    }
+
+    #[test]
+    fn subsequences_keep_their_order_and_shrink_away_elements() {
+        let xs = sample(Size(30), 20, subsequence(vec![1, 2, 3, 4, 5]));
+        assert!(xs.iter().all(|xs| xs.windows(2).all(|w| w[0] < w[1])));
+        let prop = |xs: Vec<u8>| property::from_bool(!xs.contains(&3));
+        let p = property::for_all_rendered(
+            subsequence(vec![1, 2, 3, 4, 5]),
+            |xs| format!("{:?}", xs),
+            &prop,
+        );
+        shrinks_to(p, vec!["[3]"]);
+    }
+
+    #[test]
+    fn shuffles_shrink_towards_the_original_order() {
+        let xs = sample(Size(30), 20, permutation((0..6).collect::<Vec<u8>>()));
+        assert!(xs.iter().all(|xs| {
+            let mut ys = xs.clone();
+            ys.sort();
+            ys == (0..6).collect::<Vec<_>>()
+        }));
+        let prop = |xs: Vec<u8>| property::from_bool(xs[0] != 3);
+        let p =
+            property::for_all_rendered(shuffle((0..5).collect()), |xs| format!("{:?}", xs), &prop);
+        shrinks_to(p, vec!["[3, 0, 1, 2, 4]"]);
+    }
+
+    #[test]
+    fn integrals_reach_the_extremes_of_the_widest_types() {
+        let xs = sample(Size(99), 100, u64(range::constant_bounded()));
+        assert!(xs.iter().any(|&x| x > i64::MAX as u64));
+        let xs = sample(
+            Size(99),
+            20,
+            u128(range::constant(u128::MAX - 1, u128::MAX)),
+        );
+        assert!(xs.iter().all(|&x| x >= u128::MAX - 1));
+        let xs = sample(
+            Size(99),
+            20,
+            i128(range::constant(i128::MIN, i128::MIN + 1)),
+        );
+        assert!(xs.iter().all(|&x| x <= i128::MIN + 1));
+        let xs = sample(Size(99), 100, i128(range::constant_bounded()));
+        assert!(xs.iter().any(|&x| x < i128::from(i64::MIN)));
+        assert!(xs.iter().any(|&x| x > i128::from(i64::MAX)));
+    }
+
+    #[test]
+    fn wide_integrals_shrink_to_the_boundary() {
+        let prop = |x: u128| property::from_bool(x < 1 << 100);
+        let p =
+            property::for_all_rendered(u128(range::constant_bounded()), |x| x.to_string(), &prop);
+        shrinks_to(p, vec![(1u128 << 100).to_string()]);
+    }
+
+    #[test]
+    fn big_integers_respect_their_range_and_shrink_to_the_boundary() {
+        let huge = num::pow(BigInt::from(10), 30);
+        let g = big_int(range::linear_from(
+            BigInt::from(0),
+            -huge.clone(),
+            huge.clone(),
+        ));
+        let xs = sample(Size(99), 100, g.clone());
+        assert!(xs.iter().all(|x| -huge.clone() <= *x && *x <= huge));
+        assert!(xs.iter().any(|x| *x > BigInt::from(u64::MAX)));
+        let xs = sample(Size(1), 20, g.clone());
+        let small = &huge / BigInt::from(99);
+        assert!(xs.iter().all(|x| -small.clone() <= *x && *x <= small));
+
+        let bound = num::pow(BigInt::from(10), 20);
+        let prop = |x: BigInt| property::from_bool(x < bound);
+        let p = property::for_all_rendered(g, |x| x.to_string(), &prop);
+        shrinks_to(p, vec![bound.to_string()]);
+
+        let lo = BigUint::from(5u32);
+        let hi = num::pow(BigUint::from(2u32), 80);
+        let xs = sample(
+            Size(50),
+            50,
+            big_uint(range::constant(lo.clone(), hi.clone())),
+        );
+        assert!(xs.iter().all(|x| lo <= *x && *x <= hi));
+    }
+
+    #[test]
+    fn ratios_respect_their_range_and_shrink_to_simple_fractions() {
+        let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let g = ratio(range::constant_from(r(0, 1), r(-1, 3), r(10, 1)));
+        let xs = sample(Size(50), 100, g);
+        assert!(xs.iter().all(|x| r(-1, 3) <= *x && *x <= r(10, 1)));
+        assert!(xs.iter().any(|x| !x.is_integer()));
+
+        // Replayed from fixed seeds so that the shrunk result is always the same.
+        let prop = |x: BigRational| property::from_bool(x < r(1, 2));
+        let g = ratio(range::linear_frac_from(r(0, 1), r(-1, 2), r(10, 1)));
+        let failure = (0..)
+            .find_map(|n| {
+                let p = property::for_all_rendered(g.clone(), |x| x.to_string(), &prop);
+                match property::report_recheck(Size(50), seed::from(n), p).status {
+                    Status::Failed(failure) => Some(failure),
+                    _ => None,
+                }
+            })
+            .unwrap();
+        assert_eq!(journal::to_vec(failure.journal), vec!["1/2"]);
+    }
+
+    #[test]
+    fn floats_grow_with_a_linear_frac_range() {
+        let g = || f64(range::linear_frac_from(0.0, -1.0e6, 1.0e6));
+        assert!(sample(Size(1), 100, g())
+            .iter()
+            .all(|x| x.abs() <= 1.0e6 / 99.0));
+        assert!(sample(Size(99), 100, g()).iter().any(|x| x.abs() > 1.0e5));
+    }
+
+    #[test]
+    fn float_specials_are_mixed_in() {
+        let xs = sample(
+            Size(50),
+            2000,
+            f64_any(specials::with_frequency(50, specials::default())),
+        );
+        assert!(xs.iter().any(|x| x.is_nan()));
+        assert!(xs.contains(&f64::INFINITY));
+        assert!(xs.contains(&f64::NEG_INFINITY));
+        assert!(xs.iter().any(|&x| x == 0.0 && x.is_sign_negative()));
+        assert!(xs.iter().any(|x| x.is_subnormal()));
+        assert!(xs.contains(&f64::MAX));
+        assert!(xs.iter().any(|x| x.is_normal() && x.abs() > 1.0e100));
+
+        let xs = sample(
+            Size(50),
+            2000,
+            f32_any(specials::with_frequency(50, specials::default())),
+        );
+        assert!(xs.iter().any(|x| x.is_nan()));
+        assert!(xs.iter().any(|x| x.is_subnormal()));
+        assert!(xs.contains(&f32::MIN));
+    }
+
+    #[test]
+    fn float_specials_are_opt_in() {
+        let range = || range::constant_from(0.0, -10.0, 10.0);
+        let xs = sample(Size(50), 500, f64_with(specials::none(), range()));
+        assert!(xs.iter().all(|&x| (-10.0..=10.0).contains(&x)));
+        let only_nan = specials::with_nan(true, specials::with_frequency(100, specials::none()));
+        let xs = sample(Size(50), 50, f32_with(only_nan, range::constant(-1.0, 1.0)));
+        assert!(xs.iter().all(|x| x.is_nan()));
+        let no_nan = specials::with_nan(false, specials::with_frequency(100, specials::default()));
+        let xs = sample(Size(50), 500, f64_any(no_nan));
+        assert!(xs.iter().all(|x| !x.is_nan()));
+    }
+
+    #[test]
+    #[should_panic(expected = "gen::specials: frequency 101 is not between 0 and 100")]
+    fn float_special_frequencies_above_100_are_rejected() {
+        specials::with_frequency(101, specials::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "gen::specials: frequency -1 is not between 0 and 100")]
+    fn float_special_frequencies_below_0_are_rejected() {
+        let specials = Specials {
+            frequency: -1,
+            ..specials::default()
+        };
+        f64_with(specials, range::constant(0.0, 1.0));
+    }
+
+    #[test]
+    fn float_specials_shrink_to_simple_values() {
+        let all = specials::with_frequency(100, specials::default());
+        let ts = sample_tree(
+            Size(50),
+            20,
+            f64_with(all, range::constant_from(0.0, -10.0, 10.0)),
+        );
+        for t in ts {
+            let simple: Vec<f64> = t.children().iter().take(2).map(|c| c.value()).collect();
+            assert_eq!(simple, vec![0.0, 1.0]);
+        }
+        let ts = sample_tree(
+            Size(50),
+            20,
+            f32_with(all, range::constant_from(5.0, 2.0, 10.0)),
+        );
+        for t in ts {
+            let simple: Vec<f32> = t.children().iter().map(|c| c.value()).collect();
+            assert_eq!(simple, vec![5.0]);
+        }
+    }
+
+    #[test]
+    fn chars_stay_valid_and_shrink_towards_their_origin() {
+        let g = char_in(range::constant('\u{d700}', '\u{e0ff}'));
+        let cs = sample(Size(50), 500, g);
+        assert!(cs.iter().all(|&c| ('\u{d700}'..='\u{e0ff}').contains(&c)));
+        assert!(cs.iter().any(|&c| c >= '\u{e000}'));
+        let cs = sample(Size(50), 500, unicode());
+        assert!(cs.iter().all(|&c| c != '\u{fffe}' && c != '\u{ffff}'));
+        assert!(sample(Size(50), 100, latin1())
+            .iter()
+            .all(|&c| c <= '\u{ff}'));
+
+        let prop = |c: char| property::from_bool(!c.is_ascii_uppercase());
+        let g = char_from(' ', ' ', '~');
+        let p = property::for_all_rendered(g, |c| format!("{:?}", c), &prop);
+        shrinks_to(p, vec!["'A'"]);
+        let prop = |c: char| property::from_bool(c < 'm');
+        let g = char_from('a', '0', 'z');
+        let p = property::for_all_rendered(g, |c| format!("{:?}", c), &prop);
+        shrinks_to(p, vec!["'m'"]);
+    }
+
+    #[test]
+    fn unicode_categories_and_scripts() {
+        let within =
+            |c: char, table: &[(char, char)]| table.iter().any(|&(lo, hi)| lo <= c && c <= hi);
+        let size = Size(30);
+        assert!(sample(size, 200, letter())
+            .iter()
+            .all(|c| c.is_alphabetic()));
+        assert!(sample(size, 200, mark())
+            .iter()
+            .all(|&c| within(c, unicode::MARK)));
+        assert!(sample(size, 200, number()).iter().all(|c| c.is_numeric()));
+        let cs = sample(size, 200, punctuation());
+        assert!(cs.iter().all(|&c| within(c, unicode::PUNCTUATION)));
+        assert!(sample(size, 200, symbol())
+            .iter()
+            .all(|&c| within(c, unicode::SYMBOL)));
+        assert!(sample(size, 200, separator())
+            .iter()
+            .all(|c| c.is_whitespace()));
+        assert!(sample(size, 200, control()).iter().all(|c| c.is_control()));
+        let cs = sample(size, 200, script(Script::Greek));
+        assert!(cs.iter().all(|&c| within(c, Script::Greek.ranges())));
+        let cs = sample(size, 200, block("Hiragana"));
+        assert!(cs.iter().all(|&c| ('\u{3040}'..='\u{309f}').contains(&c)));
+    }
+
+    #[test]
+    #[should_panic(expected = "no block called \"Klingon\"")]
+    fn unknown_blocks_are_rejected() {
+        block("Klingon");
+    }
+
+    #[test]
+    fn interesting_unicode_covers_the_edge_cases() {
+        let cs = sample(Size(30), 2000, unicode_interesting());
+        assert!(cs.iter().any(|c| c.is_ascii_alphabetic()));
+        assert!(cs.iter().any(|&c| ('\u{300}'..='\u{36f}').contains(&c)));
+        assert!(cs.iter().any(|&c| ('\u{200b}'..='\u{200f}').contains(&c)));
+        assert!(cs.iter().any(|&c| c > '\u{ffff}'));
+        assert!(cs.iter().any(|&c| c.is_whitespace() && c != ' '));
+    }
+
+    // Whether `s` is in the language of `^[a-c]{2,4}-(foo|ba+r)\d?$`.
+    fn in_language(s: &str) -> bool {
+        let (head, tail) = match s.find('-') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => return false,
+        };
+        let tail = tail.trim_end_matches(|c: char| c.is_ascii_digit());
+        let digits = s.len() - s.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        (2..=4).contains(&head.len())
+            && head.chars().all(|c| ('a'..='c').contains(&c))
+            && digits <= 1
+            && (tail == "foo"
+                || (tail.len() >= 3
+                    && tail.starts_with('b')
+                    && tail.ends_with('r')
+                    && tail[1..tail.len() - 1].chars().all(|c| c == 'a')))
+    }
+
+    fn all_in_language(depth: usize, t: &Tree<String>) -> bool {
+        in_language(&t.value())
+            && (depth == 0 || t.children().iter().all(|c| all_in_language(depth - 1, c)))
+    }
+
+    #[test]
+    fn regex_strings_and_their_shrinks_match() {
+        let g = from_regex(r"^[a-c]{2,4}-(foo|ba+r)\d?$");
+        for s in sample(Size(30), 100, g.clone()) {
+            assert!(in_language(&s), "{:?}", s);
+        }
+        for n in 0..10 {
+            let t = random::run(seed::from(n), Size(30), to_random(g.clone()));
+            assert!(all_in_language(3, &t));
+        }
+    }
+
+    #[test]
+    fn regex_strings_shrink_to_the_simplest_match() {
+        let prop = |_: String| property::from_bool(false);
+        let g = from_regex(r"(x|y|z)[d-f]{2,}(?:ab|c)*");
+        let p = property::for_all_rendered(g, |s| s.clone(), &prop);
+        shrinks_to(p, vec!["xdd"]);
+    }
+
+    #[test]
+    fn regex_classes_respect_negation() {
+        let xs = sample(Size(30), 100, from_regex("[^a-z\n]{5}"));
+        assert!(xs.iter().all(|s| s.chars().count() == 5));
+        assert!(xs
+            .iter()
+            .all(|s| !s.chars().any(|c| c.is_ascii_lowercase() || c == '\n')));
+        let xs = sample(Size(30), 100, from_regex("."));
+        assert!(xs.iter().all(|s| s != "\n"));
+        assert!(xs.iter().any(|s| !s.is_ascii()));
+    }
+
+    #[test]
+    #[should_panic(expected = "gen::from_regex: unclosed group at position 2")]
+    fn invalid_regexes_are_rejected() {
+        from_regex("(a");
+    }
+
     #[test]
     fn print_sample_works() {
         print_sample(alpha());
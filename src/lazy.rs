@@ -38,7 +38,7 @@ where
         }
     }
 
-    pub fn map<F, B>(&self, f: F) -> Lazy<B>
+    pub fn map<F, B>(&self, f: F) -> Lazy<'_, B>
     where
         F: Fn(A) -> B + 'a,
         B: Clone + 'a,
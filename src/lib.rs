@@ -1,15 +1,21 @@
+extern crate hedgehog_derive;
 #[macro_use]
 extern crate num_derive;
 extern crate num;
 extern crate rand;
 extern crate rand_core;
 
+pub mod arbitrary;
 pub mod gen;
 #[macro_use]
 pub mod lazy;
 pub mod property;
 pub mod random;
 pub mod range;
+pub mod regex;
 pub mod seed;
 pub mod shrink;
 pub mod tree;
+pub mod unicode;
+
+pub use hedgehog_derive::property;
@@ -1,13 +1,17 @@
 use crate::random;
-use crate::random::Random;
 use crate::range::Size;
 use crate::seed;
 use crate::seed::Seed;
 use crate::tree;
 use crate::tree::Tree;
+use arbitrary::Arbitrary;
 use gen::Gen;
-use std::fmt::Display;
+use std::any::Any;
+use std::cell::Cell;
+use std::fmt::{Debug, Display};
+use std::panic;
 use std::rc::Rc;
+use std::sync::Once;
 
 #[derive(Clone)]
 pub struct Journal(Vec<String>);
@@ -28,8 +32,16 @@ pub struct Property<'a, A>(Gen<'a, (Journal, Result<A>)>)
 where
     A: Clone;
 
+/// Everything needed to reproduce a falsified test case with `property::recheck`.
+pub struct Failure {
+    pub size: Size,
+    pub seed: Seed,
+    pub shrinks: isize, // isize -> Shrinks
+    pub journal: Journal,
+}
+
 pub enum Status {
-    Failed((isize, Journal)), // isize -> Shrinks
+    Failed(Failure),
     GaveUp,
     Ok,
 }
@@ -40,8 +52,90 @@ pub struct Report {
     pub status: Status,
 }
 
+/// Settings for a test run. Start from `config::default()` and adjust it with the `config::with_*`
+/// functions, which mirror `withTests`, `withDiscards` and `withShrinks` in Haskell hedgehog.
+#[derive(Clone)]
+pub struct Config {
+    /// Number of successful tests required for the property to pass.
+    pub tests: isize,
+    /// Number of discarded tests after which the runner gives up.
+    pub discards: isize,
+    /// Maximum number of shrinks to apply to a failure, or `None` to shrink until no smaller
+    /// counterexample is found.
+    pub shrinks: Option<isize>,
+    /// Size of the first test, which the size also wraps back to once it would exceed `max_size`.
+    pub min_size: Size,
+    pub max_size: Size,
+    /// Computes the size of the next test from the size of the previous one.
+    pub next_size: Rc<dyn Fn(Size) -> Size>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            tests: 100,
+            discards: 100,
+            shrinks: None,
+            min_size: Size(1),
+            max_size: Size(100),
+            next_size: Rc::new(|size| Size(size.0 + 1)),
+        }
+    }
+}
+
+pub mod config {
+    use super::*;
+
+    pub fn default() -> Config {
+        Config::default()
+    }
+
+    pub fn with_tests(tests: isize, config: Config) -> Config {
+        Config { tests, ..config }
+    }
+
+    pub fn with_discards(discards: isize, config: Config) -> Config {
+        Config { discards, ..config }
+    }
+
+    pub fn with_shrinks(shrinks: isize, config: Config) -> Config {
+        Config {
+            shrinks: Some(shrinks),
+            ..config
+        }
+    }
+
+    pub fn with_size_range(min_size: Size, max_size: Size, config: Config) -> Config {
+        Config {
+            min_size,
+            max_size,
+            ..config
+        }
+    }
+
+    pub fn with_size_schedule<F>(next_size: F, config: Config) -> Config
+    where
+        F: Fn(Size) -> Size + 'static,
+    {
+        Config {
+            next_size: Rc::new(next_size),
+            ..config
+        }
+    }
+
+    /// The size following `size` under `config`, wrapping back to `min_size` past `max_size`.
+    pub fn next_size(config: &Config, size: Size) -> Size {
+        let next = (config.next_size)(size);
+        if size >= config.max_size || next > config.max_size {
+            config.min_size
+        } else {
+            next.max(config.min_size)
+        }
+    }
+}
+
 pub mod tuple {
-    pub fn first<'a, F, A, B, C>(f: F, x: A, y: B) -> (C, B)
+    pub fn first<F, A, B, C>(f: F, x: A, y: B) -> (C, B)
     where
         A: Clone,
         B: Clone,
@@ -51,7 +145,7 @@ pub mod tuple {
         (f(x), y)
     }
 
-    pub fn second<'a, F, A, B, C>(f: F, x: A, y: B) -> (A, C)
+    pub fn second<F, A, B, C>(f: F, x: A, y: B) -> (A, C)
     where
         A: Clone,
         B: Clone,
@@ -191,24 +285,34 @@ pub mod pretty {
         )
     }
 
-    // isize -> Tests, isize -> Discards, isize -> Shrinks
-    pub fn render_failed(
-        tests: isize,
-        discards: isize,
-        shrinks: isize,
-        journal: Journal,
-    ) -> String {
+    pub fn render_recheck(size: Size, seed: Seed) -> String {
+        format!(
+            "This failure can be reproduced by running:\n\
+             > property::recheck({:?}, \"{}\".parse().unwrap(), prop)\n\
+             or by rerunning the test with {}={} {}={}",
+            size,
+            seed,
+            property::SEED_VAR,
+            seed,
+            property::SIZE_VAR,
+            size.0
+        )
+    }
+
+    // isize -> Tests, isize -> Discards
+    pub fn render_failed(tests: isize, discards: isize, failure: Failure) -> String {
         let mut s = format!(
             "*** Failed! Falsifiable (after {}{}{}):",
             render_tests(tests),
-            render_and_shrinks(shrinks),
-            render_discards(discards),
+            render_and_shrinks(failure.shrinks),
+            render_and_discards(discards),
         );
-        journal::to_vec(journal)
-            .iter()
-            .for_each(|entry| s.push_str(entry));
-        // discard extra newline?
-        s.truncate(s.len() - 1);
+        journal::to_vec(failure.journal).iter().for_each(|entry| {
+            s.push('\n');
+            s.push_str(entry)
+        });
+        s.push('\n');
+        s.push_str(&render_recheck(failure.size, failure.seed));
         s
     }
 }
@@ -220,30 +324,48 @@ pub mod report {
         match report.status {
             Status::Ok => pretty::render_ok(report.tests),
             Status::GaveUp => pretty::render_gave_up(report.tests, report.discards),
-            Status::Failed((shrinks, journal)) => {
-                pretty::render_failed(report.tests, report.discards, shrinks, journal)
+            Status::Failed(failure) => {
+                pretty::render_failed(report.tests, report.discards, failure)
             }
         }
     }
 
-    // We could do this if we implemented the exceptions as Error.
-    // then we could return them via Result.
-    // Which would be a nice idiomatic rust pattern.
-    //pub fn try_raise(report: Report) -> String {
-    //match report.status {
-    //Status::Ok =>
-    //pretty::render_ok(report.tests),
-    //Status::GaveUp =>
-    //pretty::render_gave_up(report.tests, report.discards),
-    //Status::Failed((shrinks, journal)) =>
-    //pretty::render_failed(report.tests, report.discards, shrinks, journal),
-    //}
-    //}
+    /// Panic with the rendered report unless every test passed, so that a falsified property
+    /// fails the surrounding `#[test]`.
+    pub fn try_raise(report: Report) {
+        match report.status {
+            Status::Ok => (),
+            Status::GaveUp | Status::Failed(_) => panic!("{}", render(report)),
+        }
+    }
 }
 
+// Exported at the crate root under another name, since `hedgehog::property` is taken by the
+// `#[property]` attribute. Use it as `property::property!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! property_do(
+    ($($body:tt)+) => {
+        $crate::do_notation!(
+            $crate::property::property::bind,
+            $crate::property::property::success,
+            []
+            $($body)+
+        )
+    };
+);
+
+/// Do-notation for properties, like `gen!` but expanding into `property::bind` and
+/// `property::success`, e.g.
+/// `property::property! { let x <- for_all_tick(g); let _ <- from_bool(x < 10); yield x }`.
+pub use property_do as property;
+
+#[allow(clippy::module_inception)]
 pub mod property {
     use super::*;
     use crate::gen;
+    use crate::lazy::Lazy;
+    use crate::random::Random;
 
     pub fn from_gen<A>(x: Gen<(Journal, Result<A>)>) -> Property<A>
     where
@@ -355,6 +477,109 @@ pub mod property {
         )
     }
 
+    fn panic_message(payload: Box<dyn Any + Send>) -> String {
+        match payload.downcast::<String>() {
+            Ok(s) => *s,
+            Err(payload) => match payload.downcast::<&str>() {
+                Ok(s) => s.to_string(),
+                Err(_) => "<non-string panic payload>".to_string(),
+            },
+        }
+    }
+
+    fn panicked<A>(payload: Box<dyn Any + Send>) -> (Journal, Result<A>)
+    where
+        A: Clone,
+    {
+        let msg = format!("*** Panicked: {}", panic_message(payload));
+        (journal::singleton(msg), Result::Failure)
+    }
+
+    // Run a continuation, turning a panic into a failed test case whose journal records the panic
+    // message. Since the continuation is rerun for every shrink, shrinking carries on through
+    // cases that panic just like it does through ordinary failures.
+    fn catch_failure<'a, F, A>(f: F) -> Gen<'a, (Journal, Result<A>)>
+    where
+        A: Clone + 'a,
+        F: FnOnce() -> Gen<'a, (Journal, Result<A>)>,
+    {
+        match panic::catch_unwind(panic::AssertUnwindSafe(f)) {
+            Ok(g) => g,
+            Err(payload) => gen::constant(panicked(payload)),
+        }
+    }
+
+    // Evaluate a test case and, lazily, its shrinks, turning a panic anywhere in them, e.g. in a
+    // generator's `map`, into a failed case. A case that panics has no shrinks of its own. Values
+    // are computed along with the list of shrinks, so a panic there takes the whole list and is
+    // reported as the only shrink instead.
+    fn catch_tree<'a, A>(t: Tree<'a, (Journal, Result<A>)>) -> Tree<'a, (Journal, Result<A>)>
+    where
+        A: Clone + 'a,
+    {
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| t.value())) {
+            Ok(x) => {
+                let xs = lazy!(
+                    match panic::catch_unwind(panic::AssertUnwindSafe(|| t.children())) {
+                        Ok(ts) => ts.into_iter().map(catch_tree).collect(),
+                        Err(payload) => vec![Tree::singleton(panicked(payload))],
+                    }
+                );
+                Tree::from_lazy(x, xs)
+            }
+            Err(payload) => Tree::singleton(panicked(payload)),
+        }
+    }
+
+    fn catch_run<'a, A>(
+        seed: Seed,
+        size: Size,
+        r: Random<'a, Tree<'a, (Journal, Result<A>)>>,
+    ) -> Tree<'a, (Journal, Result<A>)>
+    where
+        A: Clone + 'a,
+    {
+        match panic::catch_unwind(panic::AssertUnwindSafe(|| random::run(seed, size, r))) {
+            Ok(t) => catch_tree(t),
+            Err(payload) => Tree::singleton(panicked(payload)),
+        }
+    }
+
+    thread_local! {
+        static QUIET: Cell<bool> = const { Cell::new(false) };
+    }
+
+    static QUIET_HOOK: Once = Once::new();
+
+    // Panics caught while running a property end up in the journal, so the panic hook printing
+    // each of them, once for every shrink, is only noise. The hook is wrapped once rather than
+    // swapped around every run, since tests run properties on several threads at once, and it is
+    // only quiet on a thread that is running a property.
+    fn quiet_panics<A, F>(f: F) -> A
+    where
+        F: FnOnce() -> A,
+    {
+        struct Restore(bool);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let quiet = self.0;
+                QUIET.with(|q| q.set(quiet));
+            }
+        }
+
+        QUIET_HOOK.call_once(|| {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !QUIET.with(|q| q.get()) {
+                    hook(info)
+                }
+            }));
+        });
+        let _restore = Restore(QUIET.with(|q| q.replace(true)));
+        f()
+    }
+
     fn bind_gen<'a, F, A, B>(
         m: Gen<'a, (Journal, Result<A>)>,
         k: F,
@@ -375,7 +600,7 @@ pub mod property {
                             let journal = journal.clone();
                             tuple::first(move |j1| journal::append(journal.clone(), j1), j, r)
                         }),
-                        k(x),
+                        catch_failure(|| k(x)),
                     ),
                 },
             ),
@@ -391,21 +616,82 @@ pub mod property {
         from_gen(bind_gen(to_gen(m), move |x| to_gen(k(x))))
     }
 
-    pub fn for_all<'a, F, A, B>(gen: Gen<'a, A>, k: &'a F) -> Property<'a, B>
+    /// Runs `k` on generated values, recording each value in the journal using `render`.
+    pub fn for_all_rendered<'a, F, R, A, B>(gen: Gen<'a, A>, render: R, k: &'a F) -> Property<'a, B>
     where
         F: Fn(A) -> Property<'a, B> + 'a,
-        A: Clone + Display + 'a,
+        R: Fn(&A) -> String + 'a,
+        A: Clone + 'a,
         B: Clone + 'a,
     {
+        let render = Rc::new(render);
         let prepend = Rc::new(move |x: A| {
-            // pretend things don't panic.
-            to_gen(bind(counter_example(&|| format!("{}", x)), move |_| {
-                k(x.clone())
-            }))
+            let render = render.clone();
+            to_gen(bind(counter_example(&|| render(&x)), move |_| k(x.clone())))
         });
         from_gen(gen::bind(gen, prepend))
     }
 
+    pub fn for_all<'a, F, A, B>(gen: Gen<'a, A>, k: &'a F) -> Property<'a, B>
+    where
+        F: Fn(A) -> Property<'a, B> + 'a,
+        A: Clone + Display + 'a,
+        B: Clone + 'a,
+    {
+        for_all_rendered(gen, |x: &A| format!("{}", x), k)
+    }
+
+    /// Like `for_all`, but generates values with their `Arbitrary` instance and records them in
+    /// the journal with `Debug`.
+    pub fn for_all_arbitrary<'a, F, A, B>(k: &'a F) -> Property<'a, B>
+    where
+        F: Fn(A) -> Property<'a, B> + 'a,
+        A: Arbitrary + Debug + 'a,
+        B: Clone + 'a,
+    {
+        for_all_rendered(A::arbitrary(), |x: &A| format!("{:?}", x), k)
+    }
+
+    /// The results a property body can return, so that plain `()` and `bool` functions can be run
+    /// as properties. A body returning `()` fails by panicking, and an `Err` is recorded in the
+    /// journal before failing.
+    pub trait IntoProperty<'a> {
+        fn into_property(self) -> Property<'a, ()>;
+    }
+
+    impl<'a> IntoProperty<'a> for () {
+        fn into_property(self) -> Property<'a, ()> {
+            success(())
+        }
+    }
+
+    impl<'a> IntoProperty<'a> for bool {
+        fn into_property(self) -> Property<'a, ()> {
+            from_bool(self)
+        }
+    }
+
+    impl<'a> IntoProperty<'a> for Property<'a, ()> {
+        fn into_property(self) -> Property<'a, ()> {
+            self
+        }
+    }
+
+    impl<'a, E> IntoProperty<'a> for std::result::Result<(), E>
+    where
+        E: Debug,
+    {
+        fn into_property(self) -> Property<'a, ()> {
+            match self {
+                Ok(()) => success(()),
+                Err(e) => {
+                    let msg = format!("*** Error: {:?}", e);
+                    bind(counter_example(&|| msg.clone()), |_| failure())
+                }
+            }
+        }
+    }
+
     pub fn for_all_tick<'a, A>(gen: Gen<'a, A>) -> Property<'a, A>
     where
         A: Clone + Display + 'a,
@@ -414,108 +700,182 @@ pub mod property {
     }
 
     // TODO: isize -> Shrinks
-    fn take_smallest<A>(t: Tree<(Journal, Result<A>)>, nshrinks: isize) -> Status
+    // Walks down the shrink tree, always following the first child that still fails. This is a
+    // loop rather than recursion so that long shrink paths cannot overflow the stack.
+    fn take_smallest<'a, A>(
+        config: &Config,
+        size: Size,
+        seed: Seed,
+        t0: Tree<'a, (Journal, Result<A>)>,
+    ) -> Status
     where
-        A: Clone,
+        A: Clone + 'a,
     {
-        let (journal, x) = t.value();
-        let xs = t.children;
-        match x {
-            Result::Failure => match xs
-                .into_iter()
-                .find(|x| result::is_failure(tree::outcome(x).1))
-            {
-                None => Status::Failed((nshrinks, journal)),
-                Some(tree) => take_smallest(tree, nshrinks + 1),
-            },
-            Result::Discard => Status::GaveUp,
-            Result::Success(_) => Status::Ok,
+        let mut t = t0;
+        let mut nshrinks = 0;
+        loop {
+            let (journal, x) = t.value();
+            match x {
+                Result::Failure => {
+                    let exhausted = config.shrinks.is_some_and(|limit| nshrinks >= limit);
+                    let smaller = if exhausted {
+                        None
+                    } else {
+                        t.children()
+                            .into_iter()
+                            .find(|x| result::is_failure(tree::outcome(x).1))
+                    };
+                    match smaller {
+                        None => {
+                            return Status::Failed(Failure {
+                                size,
+                                seed,
+                                shrinks: nshrinks,
+                                journal,
+                            })
+                        }
+                        Some(tree) => {
+                            t = tree;
+                            nshrinks += 1;
+                        }
+                    }
+                }
+                Result::Discard => return Status::GaveUp,
+                Result::Success(_) => return Status::Ok,
+            }
         }
     }
 
+    /// Environment variable holding a seed (as printed by `report::render`) to start from instead
+    /// of a random one.
+    pub const SEED_VAR: &str = "HEDGEHOG_SEED";
+
+    /// Environment variable holding the size to start from, used together with `SEED_VAR`.
+    pub const SIZE_VAR: &str = "HEDGEHOG_SIZE";
+
+    pub(super) fn start_from(
+        default_size: Size,
+        seed: Option<String>,
+        size: Option<String>,
+    ) -> (Size, Seed) {
+        let seed = match seed {
+            None => seed::random(),
+            Some(s) => s
+                .parse()
+                .unwrap_or_else(|e| panic!("{}: invalid seed {:?}: {}", SEED_VAR, s, e)),
+        };
+        let size = match size {
+            None => default_size,
+            Some(s) => match s.trim().parse() {
+                Ok(n) if (0..=100).contains(&n) => Size(n),
+                Ok(_) => panic!(
+                    "{}: invalid size {:?}: must be between 0 and 100",
+                    SIZE_VAR, s
+                ),
+                Err(e) => panic!("{}: invalid size {:?}: {}", SIZE_VAR, s, e),
+            },
+        };
+        (size, seed)
+    }
+
+    pub fn report_with(config: Config, p: Property<()>) -> Report {
+        let (size, seed) = start_from(
+            config.min_size,
+            std::env::var(SEED_VAR).ok(),
+            std::env::var(SIZE_VAR).ok(),
+        );
+        report_from(&config, size, seed, p)
+    }
+
     // TODO: isize
     pub fn report_tick(n: isize, p: Property<()>) -> Report {
+        report_with(config::with_tests(n, config::default()), p)
+    }
+
+    // Runs `config.tests` tests starting from `size` and `seed`. Each test case is generated from
+    // the left half of a split of the current seed, and a failure records the seed *before* the
+    // split so that running this again for a single test replays the very same case.
+    fn report_from(config: &Config, size0: Size, seed0: Seed, p: Property<()>) -> Report {
+        quiet_panics(|| run_tests(config, size0, seed0, p))
+    }
+
+    fn run_tests(config: &Config, size0: Size, seed0: Seed, p: Property<()>) -> Report {
         let random = gen::to_random(to_gen(p));
-        let next_size = |size: Size| {
-            if size.0 >= 100 {
-                Size(1)
-            } else {
-                Size(size.0 + 1)
-            }
-        };
 
         // TODO: isize -> tests, isize -> disacards
-        pub fn loop0<'a, F>(
-            seed: Seed,
-            size: Size,
-            tests: isize,
-            discards: isize,
-            n: isize,
-            random: Random<'a, Tree<'a, (Journal, Result<()>)>>,
-            next_size: F,
-        ) -> Report
-        where
-            F: Fn(Size) -> Size,
-        {
-            if tests == n {
-                Report {
+        let mut seed = seed0;
+        let mut size = size0;
+        let mut tests = 0;
+        let mut discards = 0;
+        loop {
+            if tests == config.tests {
+                return Report {
                     tests,
                     discards,
                     status: Status::Ok,
-                }
-            } else if discards >= 100 {
-                Report {
+                };
+            } else if discards >= config.discards {
+                return Report {
                     tests,
                     discards,
                     status: Status::GaveUp,
-                }
-            } else {
-                let (seed1, seed2) = seed::split(seed);
-                let result = random::run(seed1, size, random.clone());
+                };
+            }
 
-                match tree::outcome(&result).1 {
-                    Result::Failure => Report {
+            let (seed1, seed2) = seed::split(seed.clone());
+            let result = catch_run(seed1, size, random.clone());
+
+            match tree::outcome(&result).1 {
+                Result::Failure => {
+                    return Report {
                         tests: tests + 1,
                         discards,
-                        status: take_smallest(result, 0),
-                    },
-                    Result::Success(()) => loop0(
-                        seed2,
-                        next_size(size),
-                        tests + 1,
-                        discards,
-                        n,
-                        random,
-                        next_size,
-                    ),
-                    Result::Discard => loop0(
-                        seed2,
-                        next_size(size),
-                        tests,
-                        discards + 1,
-                        n,
-                        random,
-                        next_size,
-                    ),
+                        status: take_smallest(config, size, seed, result),
+                    }
                 }
+                Result::Success(()) => tests += 1,
+                Result::Discard => discards += 1,
             }
-        }
 
-        let seed = seed::random();
-        loop0(seed, Size(1), 0, 0, n, random, next_size)
+            seed = seed2;
+            size = config::next_size(config, size);
+        }
     }
 
     pub fn report(p: Property<()>) -> Report {
-        report_tick(100, p)
+        report_with(config::default(), p)
+    }
+
+    pub fn check_with(config: Config, p: Property<()>) {
+        report::try_raise(report_with(config, p))
     }
 
     // TODO: isize -> tests
     pub fn check_tick(n: isize, p: Property<()>) {
-        report_tick(n, p);
+        report::try_raise(report_tick(n, p))
     }
 
     pub fn check(p: Property<()>) {
-        report(p);
+        report::try_raise(report(p))
+    }
+
+    /// Replay the single test case identified by the `size` and `seed` of a failure report,
+    /// shrinking it again exactly as the original run did. Pass the same `config` as the original
+    /// run so that its shrink limit applies again.
+    pub fn report_recheck_with(config: Config, size: Size, seed: Seed, p: Property<()>) -> Report {
+        report_from(&config::with_tests(1, config), size, seed, p)
+    }
+
+    pub fn report_recheck(size: Size, seed: Seed, p: Property<()>) -> Report {
+        report_recheck_with(config::default(), size, seed, p)
+    }
+
+    pub fn recheck_with(config: Config, size: Size, seed: Seed, p: Property<()>) {
+        report::try_raise(report_recheck_with(config, size, seed, p))
+    }
+
+    pub fn recheck(size: Size, seed: Seed, p: Property<()>) {
+        report::try_raise(report_recheck(size, seed, p))
     }
 
     pub fn check_bool(g: Property<bool>) {
@@ -536,3 +896,280 @@ pub mod property {
         print!("{}", report::render(report(p)))
     }
 }
+
+/// Helpers for the tests of the other modules.
+#[cfg(test)]
+pub(crate) mod testing {
+    use super::*;
+    use std::fmt::Debug;
+
+    /// The failure in `r`, panicking if the property was not falsified.
+    pub fn falsified(r: Report) -> Failure {
+        match r.status {
+            Status::Failed(failure) => failure,
+            _ => panic!("expected the property to be falsified"),
+        }
+    }
+
+    /// Check that `p` fails and that its counterexample shrinks to `expected`.
+    pub fn shrinks_to<S>(p: Property<()>, expected: Vec<S>)
+    where
+        S: Debug,
+        String: PartialEq<S>,
+    {
+        let failure = falsified(property::report(p));
+        assert_eq!(journal::to_vec(failure.journal), expected);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::testing::{falsified, shrinks_to};
+    use super::*;
+    use crate::gen;
+    use crate::range;
+
+    #[test]
+    fn check_passes_when_property_holds() {
+        let prop = |x: u8| property::from_bool(x <= 15);
+        property::check(property::for_all(gen::u8(range::constant(0, 15)), &prop));
+    }
+
+    #[test]
+    #[should_panic(expected = "*** Failed! Falsifiable")]
+    fn check_panics_when_property_is_falsified() {
+        let prop = |x: u8| property::from_bool(x < 10);
+        property::check(property::for_all(gen::u8(range::constant(0, 15)), &prop));
+    }
+
+    #[test]
+    fn render_includes_shrunk_counterexample() {
+        let prop = |x: u8| property::from_bool(x < 10);
+        let r = property::report(property::for_all(gen::u8(range::constant(0, 15)), &prop));
+        let lines: Vec<String> = report::render(r).lines().map(String::from).collect();
+        assert!(lines[0].starts_with("*** Failed! Falsifiable (after "));
+        assert_eq!(lines[1], "10");
+        assert_eq!(lines[2], "This failure can be reproduced by running:");
+        assert!(lines[3].starts_with("> property::recheck(Size("));
+        assert!(lines[4].starts_with("or by rerunning the test with HEDGEHOG_SEED="));
+    }
+
+    #[test]
+    fn wide_ranges_shrink_to_the_boundary() {
+        let prop = |x: u64| property::from_bool(x < 1000);
+        let g = gen::u64(range::constant(0, 1_000_000_000));
+        shrinks_to(property::for_all(g, &prop), vec!["1000"]);
+    }
+
+    #[test]
+    fn panics_are_failures_that_still_shrink() {
+        let prop = |x: u8| {
+            assert!(x < 10, "too big");
+            property::success(())
+        };
+        shrinks_to(
+            property::for_all(gen::u8(range::constant(0, 15)), &prop),
+            vec!["10", "*** Panicked: too big"],
+        );
+    }
+
+    #[test]
+    fn panics_in_generators_are_failures() {
+        let prop = |_: u8| property::success(());
+        let g = gen::map(
+            Rc::new(|x: u8| {
+                assert!(x < 10, "generated {}", x);
+                x
+            }),
+            gen::u8(range::constant(0, 15)),
+        );
+        let journal =
+            journal::to_vec(falsified(property::report(property::for_all(g, &prop))).journal);
+        assert_eq!(journal.len(), 1);
+        assert!(journal[0].starts_with("*** Panicked: generated "));
+    }
+
+    #[test]
+    fn panics_while_shrinking_are_failures() {
+        let prop = |x: u8| property::from_bool(x < 7);
+        let g = gen::shrink(
+            Rc::new(|x: u8| if x > 7 { vec![7] } else { vec![] }),
+            gen::u8(range::constant(8, 100)),
+        );
+        let g = gen::map(
+            Rc::new(|x: u8| {
+                assert!(x != 7, "seven");
+                x
+            }),
+            g,
+        );
+        let failure = falsified(property::report(property::for_all(g, &prop)));
+        assert_eq!(failure.shrinks, 1);
+        assert_eq!(
+            journal::to_vec(failure.journal),
+            vec!["*** Panicked: seven"]
+        );
+    }
+
+    #[test]
+    fn do_notation_binds_properties() {
+        let p = crate::property::property! {
+            let x <- property::for_all_tick(gen::u8(range::constant(0, 15)));
+            let y <- property::for_all_tick(gen::u8(range::constant(0, 15)));
+            let _ <- property::from_bool(x < 10 || y < 5);
+            yield ()
+        };
+        shrinks_to(p, vec!["10", "5"]);
+    }
+
+    #[test]
+    fn report_with_runs_the_configured_number_of_tests() {
+        let prop = |_: u8| property::success(());
+        let config = config::with_tests(7, config::default());
+        let r = property::report_with(
+            config,
+            property::for_all(gen::u8(range::constant(0, 15)), &prop),
+        );
+        assert!(matches!(r.status, Status::Ok));
+        assert_eq!(r.tests, 7);
+    }
+
+    #[test]
+    fn report_with_gives_up_after_the_configured_discards() {
+        let prop = |_: u8| property::discard();
+        let config = config::with_discards(3, config::default());
+        let r = property::report_with(
+            config,
+            property::for_all(gen::u8(range::constant(0, 15)), &prop),
+        );
+        assert!(matches!(r.status, Status::GaveUp));
+        assert_eq!(r.discards, 3);
+    }
+
+    #[test]
+    fn report_with_stops_shrinking_at_the_limit() {
+        let prop = |x: u8| property::from_bool(x < 10);
+        let g = gen::u8(range::constant(0, 15));
+        let config = config::with_shrinks(0, config::default());
+        let r = property::report_with(config, property::for_all(g, &prop));
+        assert_eq!(falsified(r).shrinks, 0);
+    }
+
+    #[test]
+    fn report_with_shrinks_exactly_up_to_the_limit() {
+        // Every case has exactly one shrink, one smaller, so the search walks 100, 99, ...
+        let prop = |x: u32| property::from_bool(x < 10);
+        let g = || gen::shrink(Rc::new(|x: u32| vec![x - 1]), gen::constant(100));
+        let shrunk = |config| {
+            let failure = falsified(property::report_with(config, property::for_all(g(), &prop)));
+            (failure.shrinks, journal::to_vec(failure.journal))
+        };
+        assert_eq!(
+            shrunk(config::with_shrinks(5, config::default())),
+            (5, vec!["95".to_string()])
+        );
+        assert_eq!(shrunk(config::default()), (90, vec!["10".to_string()]));
+    }
+
+    #[test]
+    fn next_size_follows_the_schedule_and_wraps() {
+        let config = config::with_size_schedule(
+            |size| Size(size.0 * 2),
+            config::with_size_range(Size(3), Size(20), config::default()),
+        );
+        let sizes: Vec<Size> = std::iter::successors(Some(Size(3)), |&size| {
+            Some(config::next_size(&config, size))
+        })
+        .take(5)
+        .collect();
+        assert_eq!(sizes, vec![Size(3), Size(6), Size(12), Size(3), Size(6)]);
+    }
+
+    #[test]
+    fn default_config_cycles_sizes_from_1_to_100() {
+        let config = config::default();
+        assert_eq!(config::next_size(&config, Size(1)), Size(2));
+        assert_eq!(config::next_size(&config, Size(99)), Size(100));
+        assert_eq!(config::next_size(&config, Size(100)), Size(1));
+    }
+
+    #[test]
+    fn check_tick_runs_a_million_tests_without_overflowing() {
+        property::check_tick(1_000_000, property::success(()));
+    }
+
+    #[test]
+    fn take_smallest_follows_deep_shrink_paths() {
+        let depth = 100_000;
+        let chain = Rc::new(move |_, _| {
+            let failing = |i: isize| (journal::singleton(i.to_string()), Result::Failure);
+            let mut t = Tree::singleton(failing(depth));
+            for i in (0..depth).rev() {
+                t = Tree::new(failing(i), vec![t]);
+            }
+            t
+        });
+        let p = property::from_gen(gen::from_random(chain));
+        let failure = falsified(property::report(p));
+        assert_eq!(failure.shrinks, depth);
+        assert_eq!(journal::to_vec(failure.journal), vec![depth.to_string()]);
+    }
+
+    #[test]
+    fn start_from_uses_the_given_seed_and_size() {
+        let (size, seed) =
+            property::start_from(Size(1), Some("42:7".to_string()), Some("13".to_string()));
+        assert_eq!(size, Size(13));
+        assert_eq!(seed.to_string(), "42:7");
+
+        let (size, _) = property::start_from(Size(5), None, None);
+        assert_eq!(size, Size(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "HEDGEHOG_SEED: invalid seed")]
+    fn start_from_rejects_an_invalid_seed() {
+        property::start_from(Size(1), Some("nope".to_string()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "HEDGEHOG_SIZE: invalid size \"-1\": must be between 0 and 100")]
+    fn start_from_rejects_a_negative_size() {
+        property::start_from(Size(1), None, Some("-1".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "HEDGEHOG_SIZE: invalid size \"101\": must be between 0 and 100")]
+    fn start_from_rejects_a_size_above_100() {
+        property::start_from(Size(1), None, Some("101".to_string()));
+    }
+
+    #[test]
+    fn recheck_replays_the_failing_case() {
+        let prop = |x: u8| property::from_bool(x < 10);
+        let g = gen::u8(range::constant(0, 15));
+        let failure = falsified(property::report(property::for_all(g.clone(), &prop)));
+
+        let size = failure.size;
+        let seed = failure.seed.clone();
+        let replayed = property::report_recheck(size, seed, property::for_all(g, &prop));
+        assert_eq!(replayed.tests, 1);
+        let again = falsified(replayed);
+        assert_eq!(again.size, failure.size);
+        assert!(again.seed == failure.seed);
+        assert_eq!(again.shrinks, failure.shrinks);
+        assert_eq!(
+            journal::to_vec(again.journal),
+            journal::to_vec(failure.journal)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "*** Failed! Falsifiable (after 1 test")]
+    fn recheck_raises_the_failure() {
+        let prop = |x: u8| property::from_bool(x < 10);
+        let g = gen::u8(range::constant(0, 15));
+        let failure = falsified(property::report(property::for_all(g.clone(), &prop)));
+        property::recheck(failure.size, failure.seed, property::for_all(g, &prop));
+    }
+}
@@ -3,7 +3,9 @@ use crate::range::Range;
 use crate::range::Size;
 use crate::seed;
 use crate::seed::Seed;
-use num::{FromPrimitive, Integer, ToPrimitive};
+use num::bigint::BigInt;
+use num::Integer;
+use rand::distributions::uniform::SampleUniform;
 use std::rc::Rc;
 
 pub type Random<'a, A> = Rc<dyn Fn(Seed, Size) -> A + 'a>;
@@ -57,12 +59,20 @@ where
 
 pub fn integral<'a, A>(range: Range<'a, A>) -> Random<'a, A>
 where
-    A: Copy + ToPrimitive + FromPrimitive + Integer,
+    A: Copy + Integer + SampleUniform,
 {
     Rc::new(move |seed, size| {
         let (lo, hi) = range::bounds(size, range.clone());
-        let (x, _) = seed::next_integer(lo.to_isize().unwrap(), hi.to_isize().unwrap(), seed);
-        FromPrimitive::from_isize(x).unwrap()
+        let (x, _) = seed::next_integer(lo, hi, seed);
+        x
+    })
+}
+
+pub fn big_int<'a>(range: Range<'a, BigInt>) -> Random<'a, BigInt> {
+    Rc::new(move |seed, size| {
+        let (lo, hi) = range::bounds(size, range.clone());
+        let (x, _) = seed::next_big_int(&lo, &hi, seed);
+        x
     })
 }
 
@@ -75,11 +85,7 @@ where
     Rc::new(move |seed, size| {
         let seed0 = seed.clone();
         let (_seed1, seed2) = seed::split(seed0);
-        unsafe_run(
-            seed2,
-            size,
-            k(unsafe_run(seed.clone(), size.clone(), r0.clone())),
-        )
+        unsafe_run(seed2, size, k(unsafe_run(seed.clone(), size, r0.clone())))
     })
 }
 
@@ -109,7 +115,7 @@ where
         let mut seed = seed0;
 
         loop {
-            if k <= 0 {
+            if k == 0 {
                 break;
             } else {
                 let (seed1, seed2) = seed::split(seed);
@@ -117,7 +123,7 @@ where
                 acc.insert(0, x);
 
                 seed = seed2;
-                k = k-1;
+                k -= 1;
                 continue;
             }
         }
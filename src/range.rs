@@ -98,28 +98,20 @@ where
 
 pub fn linear<'a, A>(x: A, y: A) -> Range<'a, A>
 where
-    A: Integer + Clone + FromPrimitive,
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
 {
     linear_from(x.clone(), x, y)
 }
 
 pub fn linear_from<'a, A>(z: A, x: A, y: A) -> Range<'a, A>
 where
-    A: Integer + Clone + FromPrimitive,
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
 {
     Range(
         z.clone(),
         Rc::new(move |sz| {
-            let x_sized = clamp(
-                x.clone(),
-                y.clone(),
-                scale_linear(sz.clone(), z.clone(), x.clone()),
-            );
-            let y_sized = clamp(
-                x.clone(),
-                y.clone(),
-                scale_linear(sz.clone(), z.clone(), y.clone()),
-            );
+            let x_sized = clamp(x.clone(), y.clone(), scale_linear(sz, z.clone(), x.clone()));
+            let y_sized = clamp(x.clone(), y.clone(), scale_linear(sz, z.clone(), y.clone()));
 
             (x_sized, y_sized)
         }),
@@ -128,33 +120,78 @@ where
 
 pub fn linear_bounded<'a, A>() -> Range<'a, A>
 where
-    A: Bounded + Integer + Clone + FromPrimitive,
+    A: Bounded + Integer + Clone + ToPrimitive + FromPrimitive,
 {
     let zero = FromPrimitive::from_isize(0).unwrap();
     linear_from(zero, A::min_value(), A::max_value())
 }
 
-pub fn clamp<'a, A>(x: A, y: A, n: A) -> A
+// The UTF-16 surrogates, which are not valid `char`s.
+const SURROGATES: std::ops::RangeInclusive<u32> = 0xd800..=0xdfff;
+const SURROGATE_COUNT: u32 = 0x800;
+
+/// The position of `c` among all `char`s, counting past the surrogate gap so that consecutive
+/// positions are consecutive characters.
+pub fn char_index(c: char) -> u32 {
+    let x = c as u32;
+    if x > *SURROGATES.end() {
+        x - SURROGATE_COUNT
+    } else {
+        x
+    }
+}
+
+/// The inverse of `char_index`.
+pub fn char_from_index(i: u32) -> char {
+    let x = if i >= *SURROGATES.start() {
+        i + SURROGATE_COUNT
+    } else {
+        i
+    };
+    std::char::from_u32(x).expect("range::char_from_index: index out of range")
+}
+
+/// A range of characters which scales linearly with the size parameter. The surrogate gap is
+/// skipped, so every size covers the same number of characters on either side of it.
+pub fn linear_char<'a>(x: char, y: char) -> Range<'a, char> {
+    linear_char_from(x, x, y)
+}
+
+pub fn linear_char_from<'a>(z: char, x: char, y: char) -> Range<'a, char> {
+    Range::map(
+        char_from_index,
+        linear_from(char_index(z), char_index(x), char_index(y)),
+    )
+}
+
+/// Clamp `n` to lie between `x` and `y`, whichever of them is the smaller. Only a partial order is
+/// needed so that floats can be clamped too.
+pub fn clamp<A>(x: A, y: A, n: A) -> A
 where
-    A: Ord,
+    A: PartialOrd,
 {
-    if x > y {
-        std::cmp::min(x, std::cmp::max(y, n))
+    let (lo, hi) = if x > y { (y, x) } else { (x, y) };
+    if n < lo {
+        lo
+    } else if n > hi {
+        hi
     } else {
-        std::cmp::min(y, std::cmp::max(x, n))
+        n
     }
 }
 
+/// A range which scales linearly with the size parameter, for fractional types such as `f64`,
+/// `f32` and `Ratio`.
 pub fn linear_frac<'a, A>(x: A, y: A) -> Range<'a, A>
 where
-    A: Num + Clone + Ord + FromPrimitive,
+    A: Num + Clone + PartialOrd + FromPrimitive,
 {
     linear_frac_from(x.clone(), x, y)
 }
 
 pub fn linear_frac_from<'a, A>(z: A, x: A, y: A) -> Range<'a, A>
 where
-    A: Num + Ord + FromPrimitive + Clone,
+    A: Num + PartialOrd + FromPrimitive + Clone,
 {
     Range(
         z.clone(),
@@ -162,60 +199,85 @@ where
             let x_sized = clamp(
                 x.clone(),
                 y.clone(),
-                scale_linear_frac(sz.clone(), z.clone(), x.clone()),
+                scale_linear_frac(sz, z.clone(), x.clone()),
             );
             let y_sized = clamp(
                 x.clone(),
                 y.clone(),
-                scale_linear_frac(sz.clone(), z.clone(), y.clone()),
+                scale_linear_frac(sz, z.clone(), y.clone()),
             );
             (x_sized, y_sized)
         }),
     )
 }
 
-pub fn scale_linear<'a, A>(sz0: Size, z0: A, n0: A) -> A
+/// Scale an integral linearly with the size parameter, i.e. `z0 + (n0 - z0) * size / 99`.
+///
+/// The distance is not computed directly since it can overflow, e.g. for `linear_bounded::<i8>()`
+/// or an unsigned origin above `n0`. Instead both ends are halved first, as in `shrink::towards`,
+/// and the remainders are scaled separately.
+pub fn scale_linear<A>(sz0: Size, z0: A, n0: A) -> A
 where
-    A: Integer + FromPrimitive + Clone,
+    A: Integer + ToPrimitive + FromPrimitive + Clone,
 {
-    let zero = FromPrimitive::from_isize(0).unwrap();
-    let ninety_nine_sz = FromPrimitive::from_isize(99).unwrap();
-    let sz = std::cmp::max(zero, std::cmp::min(ninety_nine_sz, sz0));
-    let sz1 = FromPrimitive::from_isize(sz.0).unwrap();
+    let sz = clamp(Size(0), Size(99), sz0).0;
+    let two: A = FromPrimitive::from_isize(2).unwrap();
     let ninety_nine: A = FromPrimitive::from_isize(99).unwrap();
-    let (diff, _) = Integer::div_rem(&((n0 - z0.clone()) * sz1), &ninety_nine);
-    z0 + diff
+    let up = n0 >= z0;
+    let (hi, lo) = if up {
+        (n0, z0.clone())
+    } else {
+        (z0.clone(), n0)
+    };
+    // `hi - lo == 2 * half + odd`, and `half == 99 * q + r`.
+    let half = hi.clone() / two.clone() - lo.clone() / two.clone();
+    let odd = (hi % two.clone()).to_isize().unwrap() - (lo % two).to_isize().unwrap();
+    let (q, r) = half.div_rem(&ninety_nine);
+    let whole = q * FromPrimitive::from_isize(sz).unwrap();
+    let rest = ((2 * r.to_isize().unwrap() + odd) * sz).div_euclid(99);
+    // `rest` can be up to 197, more than `i8` holds, so it is added in two halves.
+    let rest_half: A = FromPrimitive::from_isize(rest.abs() / 2).unwrap();
+    let rest_other: A = FromPrimitive::from_isize(rest.abs() - rest.abs() / 2).unwrap();
+    // The partial sums move monotonically from `z0` to the result, so none of them overflow.
+    let step = |acc: A, x: A, forward: bool| {
+        if forward == up {
+            acc + x
+        } else {
+            acc - x
+        }
+    };
+    let acc = step(z0, whole.clone(), true);
+    let acc = step(acc, rest_half, rest >= 0);
+    let acc = step(acc, rest_other, rest >= 0);
+    step(acc, whole, true)
 }
 
-// FIXME All these frac ones need to be Ratio
-// although Ratio and Rational are not traits!
-pub fn scale_linear_frac<'a, A>(sz0: Size, z0: A, n0: A) -> A
+pub fn scale_linear_frac<A>(sz0: Size, z0: A, n0: A) -> A
 where
-    A: Num + Ord + FromPrimitive + Clone,
+    A: Num + PartialOrd + FromPrimitive + Clone,
 {
-    let zero = FromPrimitive::from_isize(0).unwrap();
-    let ninety_nine_sz = FromPrimitive::from_isize(99).unwrap();
-    let sz = std::cmp::max(zero, std::cmp::min(ninety_nine_sz, sz0));
+    let sz = clamp(Size(0), Size(99), sz0);
     let sz1: A = FromPrimitive::from_isize(sz.0).unwrap();
     let ninety_nine: A = FromPrimitive::from_isize(99).unwrap();
-    let diff = (n0 - z0.clone()) * (sz1 / ninety_nine);
+    // Multiplying before dividing keeps `Ratio` exact, and avoids truncating `sz / 99` to 0.
+    let diff = (n0 - z0.clone()) * sz1 / ninety_nine;
     z0 + diff
 }
 
-// FIXME I'm not even sure how this works with the constraint of `Integer` + `Float`
-// I'm guessing here that if we can strip our dependency on `num` for generalised numbers
-// and simply work out how to do it safely within the context of type assertions
-// that rust will figure it out for us.
+/// A range which scales exponentially with the size parameter, so that small values are common
+/// and large ones still show up at large sizes.
 pub fn exponential<'a, A>(x: A, y: A) -> Range<'a, A>
 where
-    A: Integer + Float + Clone + ToPrimitive + FromPrimitive,
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
 {
     exponential_from(x.clone(), x, y)
 }
 
+/// Like `exponential`, but scaling out from an origin `z` towards both bounds, clamped to
+/// `[x, y]` in the same way as `linear_from`.
 pub fn exponential_from<'a, A>(z: A, x: A, y: A) -> Range<'a, A>
 where
-    A: Clone + Ord + Integer + Float + ToPrimitive + FromPrimitive,
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
 {
     Range(
         z.clone(),
@@ -237,52 +299,85 @@ where
 
 pub fn exponential_bounded<'a, A>() -> Range<'a, A>
 where
-    A: Bounded + Integer + FromPrimitive + Float,
+    A: Bounded + Integer + Clone + ToPrimitive + FromPrimitive,
 {
     let zero = FromPrimitive::from_isize(0).unwrap();
-    exponential_from(zero, Bounded::min_value(), Bounded::max_value())
+    exponential_from(zero, A::min_value(), A::max_value())
 }
 
 pub fn exponential_float<'a, A>(x: A, y: A) -> Range<'a, A>
 where
-    A: Float + Ord + FromPrimitive,
+    A: Float + FromPrimitive,
 {
-    exponential_float_from(x.clone(), x, y)
+    exponential_float_from(x, x, y)
 }
 
 pub fn exponential_float_from<'a, A>(z: A, x: A, y: A) -> Range<'a, A>
 where
-    A: Float + Ord + FromPrimitive,
+    A: Float + FromPrimitive,
 {
     Range(
-        z.clone(),
+        z,
         Rc::new(move |sz| {
-            let x_sized = clamp(
-                x.clone(),
-                y.clone(),
-                scale_exponential_float(sz, z.clone(), x.clone()),
-            );
-            let y_sized = clamp(
-                x.clone(),
-                y.clone(),
-                scale_exponential_float(sz, z.clone(), y.clone()),
-            );
+            let x_sized = clamp(x, y, scale_exponential_float(sz, z, x));
+            let y_sized = clamp(x, y, scale_exponential_float(sz, z, y));
             (x_sized, y_sized)
         }),
     )
 }
 
-pub fn scale_exponential<'a, A>(sz: Size, z0: A, n0: A) -> A
+/// Scale an integral exponentially with the size parameter, as in Haskell hedgehog: one more
+/// than the distance from `z0` to `n0` is raised to the power `size / 99`, in `f64`, and rounded
+/// half to even. Distances too large for `f64`, which only big integers can have, are scaled
+/// through their logarithm instead.
+pub fn scale_exponential<A>(sz0: Size, z0: A, n0: A) -> A
 where
-    A: Integer + Float + FromPrimitive + ToPrimitive,
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
 {
-    let z: A = FromPrimitive::from_f64(z0.to_f64().unwrap()).unwrap();
-    let n: A = FromPrimitive::from_f64(n0.to_f64().unwrap()).unwrap();
+    let sz = clamp(Size(0), Size(99), sz0);
+    if sz == Size(99) {
+        return n0;
+    }
+    let t = sz.0 as f64 / 99.0;
+    match (z0.to_f64(), n0.to_f64()) {
+        (Some(z), Some(n)) if (n - z).is_finite() => {
+            let diff = (((n - z).abs() + 1.0).powf(t) - 1.0) * (n - z).signum();
+            // Rounding can overshoot the end of the type, in which case `n0` is as far as we go.
+            A::from_f64((z + diff).round_ties_even()).unwrap_or(n0)
+        }
+        _ => scale_exponential_big(t, z0, n0),
+    }
+}
 
-    FromPrimitive::from_f64(scale_exponential_float(sz, z, n).to_f64().unwrap().round()).unwrap()
+fn scale_exponential_big<A>(t: f64, z: A, n: A) -> A
+where
+    A: Integer + Clone + ToPrimitive + FromPrimitive,
+{
+    let word: A = FromPrimitive::from_u64(1 << 32).unwrap();
+    let up = n >= z;
+    let mut m = if up { n - z.clone() } else { z.clone() - n };
+    let mut words = 0;
+    while m.to_f64().is_none_or(|x| x >= 2f64.powi(64)) {
+        m = m / word.clone();
+        words += 1;
+    }
+    // The distance is about `m * 2^(32 * words)`, far beyond 1, so the `+ 1` and `- 1` around the
+    // power do not matter.
+    let log2 = t * (m.to_f64().unwrap().log2() + f64::from(32 * words));
+    let extra = ((log2 - 1000.0) / 32.0).ceil().max(0.0) as i32;
+    let mut diff: A =
+        FromPrimitive::from_f64(2f64.powf(log2 - f64::from(32 * extra)).round()).unwrap();
+    for _ in 0..extra {
+        diff = diff * word.clone();
+    }
+    if up {
+        z + diff
+    } else {
+        z - diff
+    }
 }
 
-pub fn scale_exponential_float<'a, A>(sz0: Size, z: A, n: A) -> A
+pub fn scale_exponential_float<A>(sz0: Size, z: A, n: A) -> A
 where
     A: Float + FromPrimitive,
 {
@@ -290,17 +385,217 @@ where
     let ninety_nine = FromPrimitive::from_isize(99).unwrap();
     let one = FromPrimitive::from_isize(1).unwrap();
     let sz = clamp(zero, ninety_nine, sz0);
-    let x: A = FromPrimitive::from_isize((sz / ninety_nine).0).unwrap();
+    let x: A = FromPrimitive::from_f64(sz.0 as f64 / 99.0).unwrap();
     let diff = (((n - z).abs() + one).powf(x) - one) * (n - z).signum();
     z + diff
 }
 
 #[cfg(test)]
 mod test {
-    //use super::*;
+    use super::*;
+    use num::bigint::BigInt;
+    use num::rational::BigRational;
 
     #[test]
     fn stub() {
         assert_eq!(1 + 1, 2);
     }
+
+    // Bounds at sizes 0 to 99 as computed by Haskell hedgehog's `scaleExponential`.
+    const ONE_TO_512_HI: [i32; 100] = [
+        1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6,
+        7, 7, 8, 8, 9, 9, 10, 10, 11, 12, 12, 13, 14, 15, 16, 17, 18, 19, 21, 22, 23, 25, 26, 28,
+        30, 32, 34, 36, 39, 41, 44, 47, 50, 53, 56, 60, 64, 68, 73, 77, 82, 88, 93, 99, 106, 113,
+        120, 128, 136, 145, 155, 165, 175, 187, 199, 212, 226, 240, 256, 273, 290, 309, 329, 351,
+        374, 398, 424, 451, 481, 512,
+    ];
+
+    const FROM_ZERO_LO: [i16; 100] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2, -2, -2, -2, -2, -2,
+        -2, -3, -3, -3, -3, -3, -4, -4, -4, -4, -5, -5, -5, -5, -6, -6, -6, -7, -7, -8, -8, -9, -9,
+        -10, -10, -11, -11, -12, -12, -13, -14, -15, -15, -16, -17, -18, -19, -20, -21, -22, -23,
+        -25, -26, -27, -29, -30, -32, -33, -35, -37, -39, -41, -43, -45, -47, -50, -52, -55, -58,
+        -61, -64, -67, -71, -74, -78, -82, -86, -90, -95, -100, -105, -110, -116, -122, -128,
+    ];
+
+    const FROM_ZERO_HI: [i16; 100] = [
+        0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5,
+        6, 6, 7, 7, 8, 8, 9, 9, 10, 11, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21, 22, 24, 26, 27, 29,
+        31, 33, 35, 38, 40, 43, 46, 49, 52, 55, 59, 63, 67, 72, 76, 81, 87, 93, 99, 105, 112, 119,
+        127, 136, 144, 154, 164, 175, 186, 198, 211, 225, 240, 255, 272, 290, 309, 329, 350, 373,
+        398, 424, 451, 481, 512,
+    ];
+
+    const I8_LO: [i8; 100] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2, -2, -2, -2, -2, -2,
+        -2, -3, -3, -3, -3, -3, -4, -4, -4, -4, -5, -5, -5, -5, -6, -6, -6, -7, -7, -8, -8, -9, -9,
+        -10, -10, -11, -11, -12, -12, -13, -14, -15, -15, -16, -17, -18, -19, -20, -21, -22, -23,
+        -25, -26, -27, -29, -30, -32, -33, -35, -37, -39, -41, -43, -45, -47, -50, -52, -55, -58,
+        -61, -64, -67, -71, -74, -78, -82, -86, -90, -95, -100, -105, -110, -116, -122, -128,
+    ];
+
+    const I8_HI: [i8; 100] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3,
+        3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 14, 15,
+        15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 26, 27, 28, 30, 31, 33, 35, 37, 38, 40, 43, 45, 47,
+        49, 52, 55, 57, 60, 63, 67, 70, 74, 77, 81, 85, 90, 94, 99, 104, 109, 115, 121, 127,
+    ];
+
+    fn bounds_at_every_size<A: Clone>(range: Range<A>) -> (Vec<A>, Vec<A>) {
+        (0..100).map(|sz| bounds(Size(sz), range.clone())).unzip()
+    }
+
+    #[test]
+    fn exponential_matches_the_reference_bounds() {
+        let (lo, hi) = bounds_at_every_size(exponential(1, 512));
+        assert!(lo.iter().all(|&x| x == 1));
+        assert_eq!(hi, ONE_TO_512_HI);
+        let (lo, hi) = bounds_at_every_size(exponential_from(0i16, -128, 512));
+        assert_eq!(lo, FROM_ZERO_LO);
+        assert_eq!(hi, FROM_ZERO_HI);
+        let (lo, hi) = bounds_at_every_size(exponential_bounded::<i8>());
+        assert_eq!(lo, I8_LO);
+        assert_eq!(hi, I8_HI);
+    }
+
+    #[test]
+    fn linear_frac_grows_smoothly_with_the_size() {
+        let range = linear_frac(-1.0e6, 1.0e6);
+        assert_eq!(bounds(Size(0), range.clone()), (-1.0e6, -1.0e6));
+        assert_eq!(
+            bounds(Size(1), range.clone()),
+            (-1.0e6, -1.0e6 + 2.0e6 / 99.0)
+        );
+        assert_eq!(bounds(Size(99), range.clone()), (-1.0e6, 1.0e6));
+        assert_eq!(bounds(Size(500), range), (-1.0e6, 1.0e6));
+
+        let range = linear_frac_from(0.0f32, -10.0, 20.0);
+        let (lo, hi) = bounds(Size(33), range.clone());
+        assert!((lo + 10.0 / 3.0).abs() < 1.0e-5 && (hi - 20.0 / 3.0).abs() < 1.0e-5);
+        assert_eq!(bounds(Size(99), range), (-10.0, 20.0));
+
+        let (_, ys) = bounds_at_every_size(linear_frac(1.0, 0.0));
+        assert!(ys.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn linear_frac_is_exact_for_ratios() {
+        let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let range = linear_frac_from(r(0, 1), r(-1, 1), r(1, 1));
+        assert_eq!(bounds(Size(33), range.clone()), (r(-1, 3), r(1, 3)));
+        assert_eq!(bounds(Size(50), range.clone()), (r(-50, 99), r(50, 99)));
+        assert_eq!(bounds(Size(99), range), (r(-1, 1), r(1, 1)));
+    }
+
+    #[test]
+    fn exponential_float_scales_between_the_bounds() {
+        let range = exponential_float_from(0.0, -1.0e6, 1.0e6);
+        assert_eq!(bounds(Size(0), range.clone()), (0.0, 0.0));
+        let (lo, hi) = bounds(Size(50), range.clone());
+        assert!(-1.0e6 < lo && lo < -1.0 && 1.0 < hi && hi < 1.0e6);
+        assert_eq!(bounds(Size(99), range), (-1.0e6, 1.0e6));
+    }
+
+    #[test]
+    fn linear_does_not_overflow_small_or_unsigned_types() {
+        fn reference(sz: isize, z: i128, n: i128) -> i128 {
+            let sz = sz.clamp(0, 99) as i128;
+            z + (n - z) * sz / 99
+        }
+        for sz in 0..100 {
+            let (lo, hi) = bounds(Size(sz), linear_bounded::<i8>());
+            assert_eq!(i128::from(lo), reference(sz, 0, -128));
+            assert_eq!(i128::from(hi), reference(sz, 0, 127));
+            let (lo, hi) = bounds(Size(sz), linear_from::<u8>(200, 3, 255));
+            assert_eq!(i128::from(lo), reference(sz, 200, 3));
+            assert_eq!(i128::from(hi), reference(sz, 200, 255));
+            let (lo, hi) = bounds(Size(sz), linear_bounded::<i64>());
+            assert_eq!(i128::from(lo), reference(sz, 0, i64::MIN.into()));
+            assert_eq!(i128::from(hi), reference(sz, 0, i64::MAX.into()));
+            let (lo, hi) = bounds(Size(sz), linear_from::<i32>(-7, -1000, 1001));
+            assert_eq!(i128::from(lo), reference(sz, -7, -1000));
+            assert_eq!(i128::from(hi), reference(sz, -7, 1001));
+        }
+        assert_eq!(bounds(Size(99), linear_bounded::<u128>()), (0, u128::MAX));
+        assert_eq!(
+            bounds(Size(99), linear_bounded::<i128>()),
+            (i128::MIN, i128::MAX)
+        );
+    }
+
+    #[test]
+    fn char_ranges_skip_the_surrogates() {
+        assert_eq!(char_index('\u{d7ff}') + 1, char_index('\u{e000}'));
+        assert_eq!(char_from_index(char_index('\u{d7ff}') + 1), '\u{e000}');
+        assert_eq!(char_from_index(char_index(std::char::MAX)), std::char::MAX);
+        let range = linear_char_from('\u{d7f0}', '\0', std::char::MAX);
+        assert_eq!(bounds(Size(0), range.clone()), ('\u{d7f0}', '\u{d7f0}'));
+        let (lo, hi) = bounds(Size(1), range.clone());
+        assert!(lo < '\u{d7f0}' && hi > '\u{e000}');
+        assert_eq!(bounds(Size(99), range), ('\0', std::char::MAX));
+        let range = linear_char('a', 'z');
+        assert_eq!(bounds(Size(50), range), ('a', 'm'));
+    }
+
+    #[test]
+    fn exponential_bounded_reaches_the_extremes_of_every_type() {
+        fn check<A>()
+        where
+            A: Bounded + Integer + Clone + ToPrimitive + FromPrimitive + std::fmt::Debug,
+        {
+            let zero: A = FromPrimitive::from_isize(0).unwrap();
+            let range = exponential_bounded::<A>();
+            assert_eq!(bounds(Size(0), range.clone()), (zero.clone(), zero));
+            assert_eq!(
+                bounds(Size(99), range.clone()),
+                (A::min_value(), A::max_value())
+            );
+            let (lo, hi) = bounds_at_every_size(range);
+            assert!(lo.windows(2).all(|w| w[0] >= w[1]));
+            assert!(hi.windows(2).all(|w| w[0] <= w[1]));
+        }
+        check::<u8>();
+        check::<i8>();
+        check::<u16>();
+        check::<i16>();
+        check::<u32>();
+        check::<i32>();
+        check::<u64>();
+        check::<i64>();
+        check::<u128>();
+        check::<i128>();
+        check::<usize>();
+        check::<isize>();
+    }
+
+    #[test]
+    fn exponential_scales_big_integers() {
+        let big = |x: i64| BigInt::from(x);
+        let (lo, hi) = bounds_at_every_size(exponential_from(big(0), big(-128), big(512)));
+        assert_eq!(
+            lo,
+            FROM_ZERO_LO
+                .iter()
+                .map(|&x| big(x.into()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            hi,
+            FROM_ZERO_HI
+                .iter()
+                .map(|&x| big(x.into()))
+                .collect::<Vec<_>>()
+        );
+
+        // Far beyond `f64`: at size 33 a third of the digits are left, give or take rounding.
+        let huge = num::pow(big(10), 600);
+        let range = exponential_from(big(0), -huge.clone(), huge.clone());
+        assert_eq!(
+            bounds(Size(99), range.clone()),
+            (-huge.clone(), huge.clone())
+        );
+        let (lo, hi) = bounds(Size(33), range);
+        assert_eq!(lo, -hi.clone());
+        assert!(num::pow(big(10), 199) < hi && hi < num::pow(big(10), 201));
+    }
 }
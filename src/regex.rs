@@ -0,0 +1,477 @@
+//! A parser for the subset of regular expression syntax that `gen::from_regex` understands:
+//! literals and escapes, `.`, classes such as `[a-z_]` and `[^0-9]`, the class escapes `\d`, `\w`
+//! and `\s` (ASCII only) and their negations, groups, alternation, the quantifiers `*`, `+`, `?`,
+//! `{n}`, `{n,}` and `{n,m}` with counts of at most `MAX_REPEAT`, and the anchors `^` at the very
+//! start of the pattern and `$` at the very end.
+
+use std::fmt;
+
+/// The largest count `parse` accepts in `{n}`, `{n,}` and `{n,m}`. Every repetition is generated,
+/// so larger counts would only make for huge strings.
+pub const MAX_REPEAT: usize = 1000;
+
+/// The parsed form of a pattern. Literals are classes with a single character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Regex {
+    /// Matches the empty string, e.g. an anchor or an empty alternative.
+    Empty,
+    /// Any character in the sorted, disjoint, inclusive ranges.
+    Class(Vec<(char, char)>),
+    Concat(Vec<Regex>),
+    Alternate(Vec<Regex>),
+    /// At least `min` and at most `max` repetitions, or unboundedly many when `max` is `None`.
+    Repeat(Box<Regex>, usize, Option<usize>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRegexError {
+    /// The offset, in characters, at which parsing failed.
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseRegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseRegexError {}
+
+pub fn parse(pattern: &str) -> Result<Regex, ParseRegexError> {
+    let mut parser = Parser {
+        chars: pattern.chars().collect(),
+        pos: 0,
+    };
+    let regex = parser.alternate()?;
+    match parser.peek() {
+        None => Ok(regex),
+        Some(_) => Err(parser.error("unmatched `)`")),
+    }
+}
+
+const DIGIT: &[(char, char)] = &[('0', '9')];
+const WORD: &[(char, char)] = &[('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')];
+const SPACE: &[(char, char)] = &[('\t', '\r'), (' ', ' ')];
+
+/// Sort and merge ranges so that they are disjoint.
+fn normalize(mut ranges: Vec<(char, char)>) -> Vec<(char, char)> {
+    ranges.sort();
+    let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+    for (lo, hi) in ranges {
+        match merged.last_mut() {
+            Some(last) if lo as u32 <= last.1 as u32 + 1 => last.1 = last.1.max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+    merged
+}
+
+/// Every character not in `ranges`, which must be normalized.
+pub fn complement(ranges: &[(char, char)]) -> Vec<(char, char)> {
+    let mut out = vec![];
+    let mut next = Some('\0');
+    for &(lo, hi) in ranges {
+        if let Some(start) = next {
+            if start < lo {
+                out.push((start, before(lo)));
+            }
+        }
+        next = after(hi);
+    }
+    if let Some(start) = next {
+        out.push((start, std::char::MAX));
+    }
+    out
+}
+
+// The neighbouring characters, stepping over the surrogates.
+fn before(c: char) -> char {
+    match c {
+        '\u{e000}' => '\u{d7ff}',
+        c => std::char::from_u32(c as u32 - 1).unwrap(),
+    }
+}
+
+fn after(c: char) -> Option<char> {
+    match c {
+        '\u{d7ff}' => Some('\u{e000}'),
+        c => std::char::from_u32(c as u32 + 1),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, message: &str) -> ParseRegexError {
+        ParseRegexError {
+            position: self.pos.min(self.chars.len()),
+            message: message.to_string(),
+        }
+    }
+
+    fn alternate(&mut self) -> Result<Regex, ParseRegexError> {
+        let mut alternatives = vec![self.concat()?];
+        while self.eat('|') {
+            alternatives.push(self.concat()?);
+        }
+        if alternatives.len() == 1 {
+            Ok(alternatives.pop().unwrap())
+        } else {
+            Ok(Regex::Alternate(alternatives))
+        }
+    }
+
+    fn concat(&mut self) -> Result<Regex, ParseRegexError> {
+        let mut items = vec![];
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.repeat()?);
+        }
+        match items.len() {
+            0 => Ok(Regex::Empty),
+            1 => Ok(items.pop().unwrap()),
+            _ => Ok(Regex::Concat(items)),
+        }
+    }
+
+    fn repeat(&mut self) -> Result<Regex, ParseRegexError> {
+        let mut regex = self.atom()?;
+        loop {
+            let (min, max) = match self.peek() {
+                Some('{') => match self.counts()? {
+                    Some(counts) => counts,
+                    None => break,
+                },
+                Some(c) => {
+                    let counts = match c {
+                        '*' => (0, None),
+                        '+' => (1, None),
+                        '?' => (0, Some(1)),
+                        _ => break,
+                    };
+                    self.pos += 1;
+                    counts
+                }
+                None => break,
+            };
+            // Laziness does not change which strings match.
+            self.eat('?');
+            regex = Regex::Repeat(Box::new(regex), min, max);
+        }
+        Ok(regex)
+    }
+
+    // Parse `{n}`, `{n,}` or `{n,m}`, leaving the position after it. A `{` which does not start
+    // one of these is a literal, and `None` is returned without consuming anything.
+    fn counts(&mut self) -> Result<Option<(usize, Option<usize>)>, ParseRegexError> {
+        let start = self.pos;
+        self.pos += 1;
+        let min = self.number();
+        let max = if self.eat(',') { self.number() } else { min };
+        match (min, self.eat('}')) {
+            (Some(min), true) if max.unwrap_or(min) > MAX_REPEAT => {
+                self.pos = start;
+                Err(self.error("repetition count is too large"))
+            }
+            (Some(min), true) if max.is_none_or(|max| min <= max) => Ok(Some((min, max))),
+            (Some(_), true) => {
+                self.pos = start;
+                Err(self.error("repetition bounds are out of order"))
+            }
+            _ => {
+                self.pos = start;
+                Ok(None)
+            }
+        }
+    }
+
+    // Saturates rather than overflows, which `counts` rejects as too large.
+    fn number(&mut self) -> Option<usize> {
+        let mut n = None;
+        while let Some(d) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.pos += 1;
+            n = Some(
+                n.unwrap_or(0usize)
+                    .saturating_mul(10)
+                    .saturating_add(d as usize),
+            );
+        }
+        n
+    }
+
+    fn atom(&mut self) -> Result<Regex, ParseRegexError> {
+        let c = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error("unexpected end of pattern")),
+        };
+        match c {
+            '(' => {
+                self.pos += 1;
+                if self.eat('?') && !self.eat(':') {
+                    return Err(self.error("only `(?:` groups are supported"));
+                }
+                let regex = self.alternate()?;
+                if !self.eat(')') {
+                    return Err(self.error("unclosed group"));
+                }
+                Ok(regex)
+            }
+            '[' => {
+                self.pos += 1;
+                self.class()
+            }
+            '.' => {
+                self.pos += 1;
+                Ok(Regex::Class(complement(&[('\n', '\n')])))
+            }
+            '^' if self.pos != 0 => Err(self.error("`^` is only supported at the start")),
+            '$' if self.pos + 1 != self.chars.len() => {
+                Err(self.error("`$` is only supported at the end"))
+            }
+            '^' | '$' => {
+                self.pos += 1;
+                Ok(Regex::Empty)
+            }
+            '*' | '+' | '?' => Err(self.error("nothing to repeat")),
+            '\\' => {
+                self.pos += 1;
+                Ok(Regex::Class(self.escape()?))
+            }
+            c => {
+                self.pos += 1;
+                Ok(Regex::Class(vec![(c, c)]))
+            }
+        }
+    }
+
+    // The ranges matched by an escape, with the position just after the backslash.
+    fn escape(&mut self) -> Result<Vec<(char, char)>, ParseRegexError> {
+        let single = |c| Ok(vec![(c, c)]);
+        match self.next() {
+            None => Err(self.error("unexpected end of pattern")),
+            Some('d') => Ok(DIGIT.to_vec()),
+            Some('D') => Ok(complement(DIGIT)),
+            Some('w') => Ok(WORD.to_vec()),
+            Some('W') => Ok(complement(WORD)),
+            Some('s') => Ok(SPACE.to_vec()),
+            Some('S') => Ok(complement(SPACE)),
+            Some('n') => single('\n'),
+            Some('t') => single('\t'),
+            Some('r') => single('\r'),
+            Some('f') => single('\u{c}'),
+            Some('v') => single('\u{b}'),
+            Some('0') => single('\0'),
+            Some('x') => self.hex(Some(2)).map(|c| vec![(c, c)]),
+            Some('u') => self.hex(None).map(|c| vec![(c, c)]),
+            Some(c) if c.is_ascii_alphanumeric() => {
+                self.pos -= 1;
+                Err(self.error("unsupported escape"))
+            }
+            Some(c) => single(c),
+        }
+    }
+
+    // `\xHH`, or `\x{H...}` and `\u{H...}` when `digits` is `None` or a brace follows.
+    fn hex(&mut self, digits: Option<usize>) -> Result<char, ParseRegexError> {
+        let braced = self.eat('{');
+        if !braced && digits.is_none() {
+            return Err(self.error("expected `{`"));
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+            self.pos += 1;
+        }
+        let hex: String = self.chars[start..self.pos].iter().collect();
+        let valid_length = if braced {
+            !hex.is_empty() && self.eat('}')
+        } else {
+            Some(hex.len()) == digits
+        };
+        let c = u32::from_str_radix(&hex, 16)
+            .ok()
+            .and_then(std::char::from_u32);
+        match c {
+            Some(c) if valid_length => Ok(c),
+            _ => Err(self.error("invalid hexadecimal escape")),
+        }
+    }
+
+    // The ranges of a class, with the position just after the `[`.
+    fn class(&mut self) -> Result<Regex, ParseRegexError> {
+        let negated = self.eat('^');
+        let mut ranges = vec![];
+        let mut first = true;
+        loop {
+            let c = match self.next() {
+                None => return Err(self.error("unclosed class")),
+                Some(']') if !first => break,
+                Some(c) => c,
+            };
+            first = false;
+            let lo = if c == '\\' {
+                let escaped = self.escape()?;
+                if escaped.len() != 1 || escaped[0].0 != escaped[0].1 {
+                    ranges.extend(escaped);
+                    continue;
+                }
+                escaped[0].0
+            } else {
+                c
+            };
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') {
+                self.pos += 1;
+                let hi = match self.next() {
+                    None => return Err(self.error("unclosed class")),
+                    Some('\\') => match self.escape()?.as_slice() {
+                        [(c, d)] if c == d => *c,
+                        _ => return Err(self.error("invalid class range")),
+                    },
+                    Some(c) => c,
+                };
+                if hi < lo {
+                    return Err(self.error("class range is out of order"));
+                }
+                ranges.push((lo, hi));
+            } else {
+                ranges.push((lo, lo));
+            }
+        }
+        let ranges = normalize(ranges);
+        let ranges = if negated { complement(&ranges) } else { ranges };
+        if ranges.is_empty() {
+            Err(self.error("class matches no characters"))
+        } else {
+            Ok(Regex::Class(ranges))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn class(ranges: &[(char, char)]) -> Regex {
+        Regex::Class(ranges.to_vec())
+    }
+
+    #[test]
+    fn parses_the_supported_syntax() {
+        assert_eq!(
+            parse("^a(b|cd)*[x-z0]{2,3}$"),
+            Ok(Regex::Concat(vec![
+                Regex::Empty,
+                class(&[('a', 'a')]),
+                Regex::Repeat(
+                    Box::new(Regex::Alternate(vec![
+                        class(&[('b', 'b')]),
+                        Regex::Concat(vec![class(&[('c', 'c')]), class(&[('d', 'd')])]),
+                    ])),
+                    0,
+                    None
+                ),
+                Regex::Repeat(Box::new(class(&[('0', '0'), ('x', 'z')])), 2, Some(3)),
+                Regex::Empty,
+            ]))
+        );
+        assert_eq!(
+            parse(r"\d+?"),
+            Ok(Regex::Repeat(Box::new(class(DIGIT)), 1, None))
+        );
+        assert_eq!(
+            parse(r"[\w-]"),
+            Ok(class(&[
+                ('-', '-'),
+                ('0', '9'),
+                ('A', 'Z'),
+                ('_', '_'),
+                ('a', 'z')
+            ]))
+        );
+        assert_eq!(
+            parse(r"\x41\u{3bb}a{"),
+            Ok(Regex::Concat(vec![
+                class(&[('A', 'A')]),
+                class(&[('λ', 'λ')]),
+                class(&[('a', 'a')]),
+                class(&[('{', '{')]),
+            ]))
+        );
+        assert_eq!(
+            parse("a{1000,}"),
+            Ok(Regex::Repeat(
+                Box::new(class(&[('a', 'a')])),
+                MAX_REPEAT,
+                None
+            ))
+        );
+        assert_eq!(
+            parse("a|"),
+            Ok(Regex::Alternate(vec![class(&[('a', 'a')]), Regex::Empty]))
+        );
+    }
+
+    #[test]
+    fn negated_classes_skip_the_surrogates() {
+        assert_eq!(
+            complement(&[('\0', '`'), ('b', '\u{d7ff}')]),
+            vec![('a', 'a'), ('\u{e000}', std::char::MAX)]
+        );
+        assert_eq!(
+            parse("[^\0-\u{10ffff}]").unwrap_err().message,
+            "class matches no characters"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        let error = |pattern| parse(pattern).unwrap_err();
+        assert_eq!(error("a)").position, 1);
+        assert_eq!(error("(a").message, "unclosed group");
+        assert_eq!(error("*").message, "nothing to repeat");
+        assert_eq!(error("[b-a]").message, "class range is out of order");
+        assert_eq!(
+            error("a{3,1}").message,
+            "repetition bounds are out of order"
+        );
+        assert_eq!(
+            error("a{99999999999}").message,
+            "repetition count is too large"
+        );
+        assert_eq!(
+            error("a{1,99999999999999999999999}").message,
+            "repetition count is too large"
+        );
+        assert_eq!(error("a^b").message, "`^` is only supported at the start");
+        assert_eq!(error("(^a)").position, 1);
+        assert_eq!(error("a$b").message, "`$` is only supported at the end");
+        assert_eq!(error("(a$)").position, 2);
+        assert_eq!(error(r"\1").message, "unsupported escape");
+        assert_eq!(error("(?i)a").message, "only `(?:` groups are supported");
+        assert_eq!(error("[ab").to_string(), "unclosed class at position 3");
+    }
+}
@@ -1,13 +1,28 @@
+use num::bigint::{BigInt, BigUint, Sign};
+use rand::distributions::uniform::SampleUniform;
 use rand::distributions::{Distribution, Uniform};
 use rand::*;
 use rand_core::{impls, RngCore};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
 
 const GOLDEN_GAMMA: u64 = 0x9e3779b97f4a7c15;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct Seed {
     value: u64,
-    gamma: u64,
+    gamma: u64, // must be odd
+}
+
+impl Seed {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn gamma(&self) -> u64 {
+        self.gamma
+    }
 }
 
 #[inline(never)]
@@ -27,6 +42,16 @@ pub fn from(x: u64) -> Seed {
     Seed { value, gamma }
 }
 
+/// Rebuild a seed from its parts, e.g. those printed in a failure report. The gamma must be odd,
+/// otherwise the generator would not go through every value before repeating.
+pub fn from_parts(value: u64, gamma: u64) -> Result<Seed, ParseSeedError> {
+    if gamma & 1 == 0 {
+        Err(ParseSeedError::EvenGamma)
+    } else {
+        Ok(Seed { value, gamma })
+    }
+}
+
 pub fn next(Seed { value, gamma }: Seed) -> (u64, Seed) {
     let value = value.wrapping_add(gamma);
     (value, Seed { value, gamma })
@@ -47,15 +72,49 @@ pub fn next_word64(s0: Seed) -> (u64, Seed) {
 
 pub fn next_word32(s0: Seed) -> (u32, Seed) {
     let (v0, s1) = next(s0);
-    (mix32(v0 as u32), s1)
+    (mix32(v0), s1)
 }
 
-// XXX Should this be BigInt?
-pub fn next_integer(lo: isize, hi: isize, mut s0: Seed) -> (isize, Seed) {
+/// Sample uniformly from `lo..=hi`. This samples at the width of `A` itself, so the full range of
+/// every integral type, `u128` and `i128` included, is available on every platform.
+pub fn next_integer<A>(lo: A, hi: A, mut s0: Seed) -> (A, Seed)
+where
+    A: SampleUniform,
+{
     let v = Uniform::from(lo..=hi).sample(&mut s0);
     (v, s0)
 }
 
+/// Like `next_integer`, but for integers of any size. Words are drawn until they fit in
+/// `hi - lo`, so every value in `lo..=hi` is equally likely.
+pub fn next_big_int(lo: &BigInt, hi: &BigInt, s0: Seed) -> (BigInt, Seed) {
+    let width = (hi - lo)
+        .to_biguint()
+        .expect("seed::next_big_int: 'lo' must not be greater than 'hi'");
+    let bits = width.bits();
+    let mask = match bits % 32 {
+        0 => u32::MAX,
+        n => (1 << n) - 1,
+    };
+    let words = bits.div_ceil(32);
+    let mut seed = s0;
+    loop {
+        let mut digits = Vec::with_capacity(words);
+        for _ in 0..words {
+            let (w, s) = next_word32(seed);
+            seed = s;
+            digits.push(w);
+        }
+        if let Some(top) = digits.last_mut() {
+            *top &= mask;
+        }
+        let x = BigUint::new(digits);
+        if x <= width {
+            return (lo + BigInt::from_biguint(Sign::Plus, x), seed);
+        }
+    }
+}
+
 pub fn next_double(lo: f64, hi: f64, mut s0: Seed) -> (f64, Seed) {
     // Could use lo..hi.into()
     let v = Uniform::from(lo..=hi).sample(&mut s0);
@@ -74,12 +133,10 @@ pub fn mix64(x: u64) -> u64 {
     z ^ (z >> 33)
 }
 
-#[allow(overflowing_literals)]
-#[allow(exceeding_bitshifts)]
-pub fn mix32(x: u32) -> u32 {
+pub fn mix32(x: u64) -> u32 {
     let y = (x ^ (x >> 33)).wrapping_mul(0xff51afd7ed558ccd);
     let z = (y ^ (y >> 33)).wrapping_mul(0xc4ceb9fe1a85ec53);
-    z >> 32
+    (z >> 32) as u32
 }
 
 pub fn mix64_variant13(x: u64) -> u64 {
@@ -98,15 +155,61 @@ pub fn mix_gamma(x: u64) -> u64 {
     }
 }
 
+/// Seeds render as `value:gamma` in decimal, which `FromStr` accepts back, so a seed printed in a
+/// failure report can be pasted into `HEDGEHOG_SEED` or parsed in code.
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.value, self.gamma)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSeedError {
+    MissingSeparator,
+    InvalidNumber(ParseIntError),
+    EvenGamma,
+}
+
+impl fmt::Display for ParseSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSeedError::MissingSeparator => {
+                f.write_str("expected a seed of the form value:gamma")
+            }
+            ParseSeedError::InvalidNumber(e) => write!(f, "invalid seed component: {}", e),
+            ParseSeedError::EvenGamma => f.write_str("seed gamma must be odd"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSeedError {}
+
+impl FromStr for Seed {
+    type Err = ParseSeedError;
+
+    fn from_str(s: &str) -> Result<Seed, ParseSeedError> {
+        let mut parts = s.trim().splitn(2, ':');
+        let value = parts.next().ok_or(ParseSeedError::MissingSeparator)?;
+        let gamma = parts.next().ok_or(ParseSeedError::MissingSeparator)?;
+        let value = value.parse().map_err(ParseSeedError::InvalidNumber)?;
+        let gamma = gamma.parse().map_err(ParseSeedError::InvalidNumber)?;
+        from_parts(value, gamma)
+    }
+}
+
 // Not sure if the RngCore config intelligently works between 32 and 64 bit
 // arch otherwise we need `#[cfg(target_pointer_width = "64")]`
 impl RngCore for Seed {
     fn next_u32(&mut self) -> u32 {
-        next_word32(self.clone()).0
+        let (x, s) = next_word32(self.clone());
+        *self = s;
+        x
     }
 
     fn next_u64(&mut self) -> u64 {
-        next_word64(self.clone()).0
+        let (x, s) = next_word64(self.clone());
+        *self = s;
+        x
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
@@ -114,16 +217,84 @@ impl RngCore for Seed {
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
-        Ok(self.fill_bytes(dest))
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
-    //use super::*;
+    use super::*;
 
     #[test]
     fn stub() {
         assert_eq!(1 + 1, 2);
     }
+
+    #[test]
+    fn next_word32_spans_the_full_width() {
+        let xs: Vec<u32> = (0..100).map(|n| next_word32(from(n)).0).collect();
+        assert!(xs.iter().any(|&x| x > u32::from(u16::MAX)));
+        assert!(xs.windows(2).any(|w| w[0] != w[1]));
+    }
+
+    #[test]
+    fn rng_advances_between_draws() {
+        let mut seed = from(42);
+        let xs: Vec<u64> = (0..4).map(|_| seed.next_u64()).collect();
+        assert!(xs.windows(2).all(|w| w[0] != w[1]));
+        assert!(seed.next_u32() != seed.next_u32());
+    }
+
+    #[test]
+    fn next_integer_covers_the_widest_types() {
+        let (x, _) = next_integer(u128::MAX - 1, u128::MAX, from(1));
+        assert!(x >= u128::MAX - 1);
+        let (y, _) = next_integer(i128::MIN, i128::MIN + 1, from(1));
+        assert!(y <= i128::MIN + 1);
+    }
+
+    #[test]
+    fn next_big_int_stays_in_bounds() {
+        let lo = BigInt::from(-3);
+        let hi = num::pow(BigInt::from(2), 70);
+        let mut seed = from(7);
+        for _ in 0..100 {
+            let (x, s) = next_big_int(&lo, &hi, seed);
+            seed = s;
+            assert!(lo <= x && x <= hi);
+        }
+        let (x, _) = next_big_int(&hi, &hi, seed);
+        assert_eq!(x, hi);
+    }
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        let seed = random();
+        let parsed: Seed = seed.to_string().parse().unwrap();
+        assert!(parsed == seed);
+    }
+
+    #[test]
+    fn display_is_value_colon_gamma() {
+        let seed = from_parts(42, 7).unwrap();
+        assert_eq!(seed.to_string(), "42:7");
+        assert_eq!((seed.value(), seed.gamma()), (42, 7));
+    }
+
+    #[test]
+    fn from_parts_rejects_an_even_gamma() {
+        assert_eq!(from_parts(42, 8), Err(ParseSeedError::EvenGamma));
+        assert!(from_parts(42, 9).is_ok());
+    }
+
+    #[test]
+    fn from_str_rejects_malformed_seeds() {
+        assert_eq!("42".parse::<Seed>(), Err(ParseSeedError::MissingSeparator));
+        assert!(matches!(
+            "42:x".parse::<Seed>(),
+            Err(ParseSeedError::InvalidNumber(_))
+        ));
+        assert_eq!("42:8".parse::<Seed>(), Err(ParseSeedError::EvenGamma));
+    }
 }
@@ -1,8 +1,10 @@
 extern crate num;
 
+use self::num::rational::BigRational;
 use self::num::{Float, FromPrimitive, Integer};
 use crate::tree;
 use crate::tree::Tree;
+use lazy::Lazy;
 use std::rc::Rc;
 
 // TODO: missing:
@@ -11,16 +13,16 @@ use std::rc::Rc;
 // This probably could be optimised for an eager language. by simply manipulating the vector
 // directly and doing the inner check, rather than returning the function here for use in a
 // pipeline a la the F# port.
-fn cons_nub<'a, A: 'a>(x: A) -> Box<dyn Fn(Vec<A>) -> Vec<A> + 'a>
+fn cons_nub<'a, A>(x: A) -> Box<dyn Fn(Vec<A>) -> Vec<A> + 'a>
 where
-    A: Integer + FromPrimitive + Copy,
+    A: PartialEq + Clone + 'a,
 {
     let cons_nub_do = move |ys0: Vec<A>| match ys0.first() {
-        None => vec![],
-        Some(&y) if x == y => ys0,
+        None => vec![x.clone()],
+        Some(y) if x == *y => ys0,
         Some(_) => {
             let mut ys1 = ys0.clone();
-            ys1.insert(0, x);
+            ys1.insert(0, x.clone());
             ys1
         }
     };
@@ -34,14 +36,9 @@ where
 {
     let mut acc = vec![];
     let mut b = b0;
-    loop {
-        if let Some((a, b1)) = f(b) {
-            acc.push(a);
-            b = b1;
-            continue;
-        } else {
-            break;
-        }
+    while let Some((a, b1)) = f(b) {
+        acc.push(a);
+        b = b1;
     }
     acc
 }
@@ -90,7 +87,7 @@ where
         vec![]
     } else {
         let xs01 = xs00.clone().into_iter().take(1).collect::<Vec<_>>();
-        let x0 = xs01.get(0).unwrap();
+        let x0 = xs01.first().unwrap();
         let xs0: Vec<_> = xs00.into_iter().skip(1).collect();
         let mut ys: Vec<_> = shrink(x0.clone())
             .into_iter()
@@ -115,15 +112,14 @@ where
 
 pub fn halves<A>(n: A) -> Vec<A>
 where
-    A: Integer + FromPrimitive + Copy,
+    A: Integer + FromPrimitive + Clone,
 {
-    let go = |x0| {
-        let zero = num::zero();
-        if x0 == zero {
+    let go = |x0: A| {
+        if x0.is_zero() {
             None
         } else {
             let two = FromPrimitive::from_isize(2).unwrap();
-            let x1 = x0 / two;
+            let x1 = x0.clone() / two;
             Some((x0, x1))
         }
     };
@@ -134,24 +130,65 @@ where
 pub fn towards<'a, A>(destination: A, x: A) -> Vec<A>
 where
     A: 'a,
-    A: Integer + FromPrimitive + Copy,
+    A: Integer + FromPrimitive + Clone,
 {
     if destination == x {
         vec![]
     } else {
         // We need to halve our operands before subtracting them as they may be using
         // the full range of the type (i.e. 'MinValue' and 'MaxValue' for 'Int32')
-        let two = FromPrimitive::from_isize(2).unwrap();
-        let diff = (x / two) - (destination / two);
+        let two: A = FromPrimitive::from_isize(2).unwrap();
+        let diff = (x.clone() / two.clone()) - (destination.clone() / two);
 
-        cons_nub(destination)(halves(diff).into_iter().map(|y| x - y).collect())
+        cons_nub(destination)(halves(diff).into_iter().map(|y| x.clone() - y).collect())
+    }
+}
+
+/// Shrink a rational number by edging towards a destination. After the destination itself, `x`
+/// is rounded towards it with ever finer denominators, and only then is the numerator shrunk
+/// while keeping the denominator, so simple fractions are found before close ones. The midpoint
+/// comes last, so that e.g. `1` can still shrink to `1/2` towards `0`.
+pub fn towards_ratio(destination: BigRational, x: BigRational) -> Vec<BigRational> {
+    if destination == x {
+        return vec![];
+    }
+    let round = |y: BigRational| {
+        if x > destination {
+            y.floor()
+        } else {
+            y.ceil()
+        }
+    };
+    let mut xs = vec![destination.clone()];
+    let mut d = destination.denom().clone();
+    while d < *x.denom() {
+        let scale = BigRational::from_integer(d.clone());
+        let y = round(&x * &scale) / scale;
+        if y != x && !xs.contains(&y) {
+            xs.push(y);
+        }
+        d *= 2;
+    }
+    let d = x.denom().lcm(destination.denom());
+    let scale = BigRational::from_integer(d.clone());
+    let n = (&x * &scale).to_integer();
+    for m in towards((&destination * &scale).to_integer(), n) {
+        let y = BigRational::new(m, d.clone());
+        if !xs.contains(&y) {
+            xs.push(y);
+        }
+    }
+    let half = (&x + &destination) / BigRational::from_integer(2.into());
+    if !xs.contains(&half) {
+        xs.push(half);
     }
+    xs
 }
 
 // TODO: rename to monomorphic variant.
 /// Shrink a floating-point number by edging towards a destination.
 /// Note we always try the destination first, as that is the optimal shrink.
-pub fn towards_float<'a, A: 'a>(destination: A, x: A) -> Vec<A>
+pub fn towards_float<A>(destination: A, x: A) -> Vec<A>
 where
     A: Float + FromPrimitive + Copy,
 {
@@ -190,30 +227,23 @@ pub fn sequence<'a, A, F>(merge: Rc<F>, xs: Vec<Tree<'a, A>>) -> Tree<'a, Vec<A>
 where
     A: Clone + 'a,
     // FIX: This is a bit silly because we don't have a LazyList type.
-    F: Fn(Vec<Tree<'a, A>>) -> Vec<Vec<Tree<'a, A>>>,
+    F: Fn(Vec<Tree<'a, A>>) -> Vec<Vec<Tree<'a, A>>> + 'a,
 {
-    let y = xs.clone().into_iter().map(|t| tree::outcome(t)).collect();
-    let ys = merge(xs)
+    let y = xs.iter().map(tree::outcome).collect();
+    let ys = lazy!(merge(xs.clone())
         .into_iter()
         .map(|v| sequence(merge.clone(), v))
-        .collect();
-    Tree::new(y, ys)
+        .collect());
+    Tree::from_lazy(y, ys)
 }
 
+/// Shrink a list by dropping runs of elements and then by shrinking the elements, see
+/// `tree::interleave`.
 pub fn sequence_list<'a, A>(xs0: Vec<Tree<'a, A>>) -> Tree<'a, Vec<A>>
 where
     A: Clone + 'a,
 {
-    sequence(
-        Rc::new(move |xs: Vec<Tree<'a, A>>| {
-            let ys = xs.clone();
-            let mut shrinks = vec(xs);
-            let mut elems = elems(Rc::new(move |t| tree::shrinks(t)), ys);
-            shrinks.append(&mut elems);
-            shrinks
-        }),
-        xs0,
-    )
+    tree::interleave(xs0)
 }
 
 pub fn sequence_elems<'a, A>(xs0: Vec<Tree<'a, A>>) -> Tree<'a, Vec<A>>
@@ -228,6 +258,7 @@ where
 
 #[cfg(test)]
 mod test {
+    use super::num::BigInt;
     use super::*;
 
     #[test]
@@ -236,6 +267,41 @@ mod test {
         assert_eq!(f(100), vec![3, 51, 76, 88, 94, 97, 99]);
     }
 
+    #[test]
+    fn towards_always_offers_the_destination() {
+        assert_eq!(towards(0, 1), vec![0]);
+        assert_eq!(towards(-5, -4), vec![-5]);
+        assert_eq!(towards(7, 7), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn towards_ratio_tries_simple_fractions_first() {
+        let r = |n: i64, d: i64| BigRational::new(BigInt::from(n), BigInt::from(d));
+        let xs = towards_ratio(r(0, 1), r(73, 100));
+        assert_eq!(&xs[..4], &[r(0, 1), r(1, 2), r(5, 8), r(11, 16)][..]);
+        assert!(xs.iter().all(|x| *x >= r(0, 1) && *x < r(73, 100)));
+        assert_eq!(
+            towards_ratio(r(1, 3), r(-1, 1)),
+            vec![r(1, 3), r(-2, 3), r(-1, 3)]
+        );
+        assert_eq!(towards_ratio(r(0, 1), r(1, 1)), vec![r(0, 1), r(1, 2)]);
+        assert!(towards_ratio(r(1, 2), r(1, 2)).is_empty());
+    }
+
+    #[test]
+    fn towards_works_for_big_integers() {
+        let f = |x| towards(BigInt::from(3), BigInt::from(x));
+        let expected: Vec<BigInt> = vec![3, 51, 76, 88, 94, 97, 99]
+            .into_iter()
+            .map(BigInt::from)
+            .collect();
+        assert_eq!(f(100), expected);
+        let huge = num::pow(BigInt::from(10), 40);
+        let xs = towards(BigInt::from(0), huge.clone());
+        assert_eq!(xs.first(), Some(&BigInt::from(0)));
+        assert!(xs.iter().all(|x| *x < huge));
+    }
+
     #[test]
     fn towards_float_works() {
         let f = |x| towards_float(100.0, x);
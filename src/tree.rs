@@ -1,16 +1,20 @@
 use lazy::Lazy;
-use std::borrow::Borrow;
+use shrink;
 use std::fmt;
 use std::fmt::{Debug, Display, Write};
 use std::rc::Rc;
 
+/// A rose tree whose children are only computed when they are first asked for. Generators build
+/// the whole shrink space as one of these, but the runner only ever forces the handful of
+/// branches it walks down while searching for the smallest counterexample.
 #[derive(Clone, Debug)]
 pub struct Tree<'a, A>
 where
     A: Clone,
 {
     thunk: Lazy<'a, A>,
-    pub children: Vec<Tree<'a, A>>,
+    // Shared so that clones of a node also share the memoised children.
+    forest: Rc<Lazy<'a, Vec<Tree<'a, A>>>>,
 }
 
 impl<'a, A> Tree<'a, A>
@@ -18,33 +22,46 @@ where
     A: 'a + Clone,
 {
     pub fn new(value: A, children: Vec<Tree<'a, A>>) -> Self {
-        let thunk = Lazy::new(value);
-        Tree { thunk, children }
+        Self::from_lazy(value, Lazy::new(children))
     }
 
-    pub fn singleton(value: A) -> Tree<'a, A> {
+    /// Build a node whose children are computed on demand.
+    pub fn from_lazy(value: A, children: Lazy<'a, Vec<Tree<'a, A>>>) -> Self {
         Tree {
             thunk: Lazy::new(value),
-            children: vec![],
+            forest: Rc::new(children),
         }
     }
 
+    pub fn singleton(value: A) -> Tree<'a, A> {
+        Self::new(value, vec![])
+    }
+
     pub fn value(&self) -> A {
         self.thunk.value()
     }
 
+    /// The immediate shrinks of this node, forcing them if they have not been computed yet.
+    pub fn children(&self) -> Vec<Tree<'a, A>> {
+        self.forest.value()
+    }
+
     pub fn expand<F>(f: Rc<F>, t: Tree<'a, A>) -> Tree<'a, A>
     where
-        F: Fn(A) -> Vec<A>,
+        F: Fn(A) -> Vec<A> + 'a,
     {
-        let mut children: Vec<Tree<'a, A>> = t
-            .children
-            .iter()
-            .map(|t| Self::expand(f.clone(), t.clone()))
-            .collect();
-        let mut zs = unfold_forest(Rc::new(move |x| x), f.clone(), t.value());
-        children.append(&mut zs);
-        Tree::new(t.value(), children)
+        let x = t.value();
+        let children = lazy!({
+            let mut children: Vec<Tree<'a, A>> = t
+                .children()
+                .into_iter()
+                .map(|t| Self::expand(f.clone(), t))
+                .collect();
+            let mut zs = unfold_forest(Rc::new(move |x| x), f.clone(), t.value());
+            children.append(&mut zs);
+            children
+        });
+        Tree::from_lazy(x, children)
     }
 }
 
@@ -54,15 +71,18 @@ where
     B: Clone + 'a,
     F: Fn(A) -> Tree<'a, B> + 'a,
 {
-    let x = t.value();
-    let xs0 = t.children;
-    let mut t1 = k(x.clone());
-    let mut xs: Vec<Tree<'a, B>> = xs0.iter().map(|m| bind(m.clone(), k.clone())).collect();
-    xs.append(&mut t1.children);
-    Tree {
-        thunk: t1.thunk,
-        children: xs,
-    }
+    let t1 = k(t.value());
+    let y = t1.value();
+    let children = lazy!({
+        let mut xs: Vec<Tree<'a, B>> = t
+            .children()
+            .into_iter()
+            .map(|m| bind(m, k.clone()))
+            .collect();
+        xs.append(&mut t1.children());
+        xs
+    });
+    Tree::from_lazy(y, children)
 }
 
 pub fn join<'a, A>(tss: Tree<'a, Tree<'a, A>>) -> Tree<'a, A>
@@ -76,18 +96,14 @@ pub fn duplicate<'a, A>(t: Tree<'a, A>) -> Tree<'a, Tree<'a, A>>
 where
     A: Clone + 'a,
 {
-    let xs = t
-        .clone()
-        .children
-        .into_iter()
-        .map(|x| duplicate(x))
-        .collect();
-    Tree::new(t, xs)
+    let t1 = t.clone();
+    let xs = lazy!(t1.children().into_iter().map(duplicate).collect());
+    Tree::from_lazy(t, xs)
 }
 
-pub fn fold<A, X, B, F, G>(f: &F, g: &G, t: Tree<A>) -> B
+pub fn fold<'a, A, X, B, F, G>(f: &F, g: &G, t: Tree<'a, A>) -> B
 where
-    A: Clone,
+    A: Clone + 'a,
     B: Clone,
     X: Clone,
     // TODO get rid of these static lifetimes
@@ -95,13 +111,13 @@ where
     G: Fn(Vec<B>) -> X + 'static,
 {
     let x = t.value();
-    let xs = t.children;
+    let xs = t.children();
     f(x, fold_forest(f, g, xs))
 }
 
-pub fn fold_forest<'a, A, X, B, F, G>(f: &F, g: &G, xs: Vec<Tree<A>>) -> X
+pub fn fold_forest<'a, A, X, B, F, G>(f: &F, g: &G, xs: Vec<Tree<'a, A>>) -> X
 where
-    A: Clone,
+    A: Clone + 'a,
     B: Clone,
     X: Clone,
     // TODO get rid of these static lifetimes
@@ -118,9 +134,9 @@ where
     fn eq(&self, other: &Self) -> bool {
         self.value() == other.value()
             && self
-                .children
+                .children()
                 .iter()
-                .zip(&other.children)
+                .zip(&other.children())
                 .all(|(x, y)| x.value() == y.value())
     }
 }
@@ -130,14 +146,11 @@ pub fn unfold<'a, A, B, F, G>(f: Rc<F>, g: Rc<G>, x: B) -> Tree<'a, A>
 where
     A: Clone + 'a,
     B: Clone + 'a,
-    F: Fn(B) -> A,
-    G: Fn(B) -> Vec<B>,
+    F: Fn(B) -> A + 'a,
+    G: Fn(B) -> Vec<B> + 'a,
 {
     let y = f(x.clone());
-    Tree {
-        thunk: Lazy::new(y),
-        children: unfold_forest(f.clone(), g.clone(), x),
-    }
+    Tree::from_lazy(y, lazy!(unfold_forest(f.clone(), g.clone(), x.clone())))
 }
 
 /// Build a list of trees from an unfolding function and a seed value.
@@ -145,11 +158,11 @@ pub fn unfold_forest<'a, A, B, F, G>(f: Rc<F>, g: Rc<G>, x: B) -> Vec<Tree<'a, A
 where
     A: Clone + 'a,
     B: Clone + 'a,
-    F: Fn(B) -> A,
-    G: Fn(B) -> Vec<B>,
+    F: Fn(B) -> A + 'a,
+    G: Fn(B) -> Vec<B> + 'a,
 {
-    g(x).iter()
-        .map(move |v| unfold(f.clone(), g.clone(), v.clone()))
+    g(x).into_iter()
+        .map(move |v| unfold(f.clone(), g.clone(), v))
         .collect()
 }
 
@@ -159,7 +172,7 @@ where
     A: Clone + 'a,
 {
     fn as_ref(&self) -> &Tree<'a, A> {
-        self.borrow()
+        self
     }
 }
 
@@ -173,11 +186,11 @@ where
     t.as_ref().value()
 }
 
-pub fn shrinks<A>(t: Tree<A>) -> Vec<Tree<A>>
+pub fn shrinks<'a, A>(t: Tree<'a, A>) -> Vec<Tree<'a, A>>
 where
-    A: Clone,
+    A: Clone + 'a,
 {
-    t.children
+    t.children()
 }
 
 // TODO: https://github.com/hedgehogqa/fsharp-hedgehog/blob/master/src/Hedgehog/Tree.fs#L84-L87
@@ -186,7 +199,8 @@ where
     A: Clone + 'a,
     F: Fn(A) -> bool + 'a,
 {
-    Tree::new(t.value(), filter_forest(f.clone(), t.children))
+    let x = t.value();
+    Tree::from_lazy(x, lazy!(filter_forest(f.clone(), t.children())))
 }
 
 pub fn filter_forest<'a, A, F>(f: Rc<F>, xs: Vec<Tree<'a, A>>) -> Vec<Tree<'a, A>>
@@ -195,7 +209,7 @@ where
     F: Fn(A) -> bool + 'a,
 {
     xs.into_iter()
-        .filter(|x| f(outcome(x.clone())))
+        .filter(|x| f(outcome(x)))
         .map(|x| filter(f.clone(), x))
         .collect()
 }
@@ -204,11 +218,65 @@ pub fn map<'a, A, B, F>(f: Rc<F>, t: Tree<'a, A>) -> Tree<'a, B>
 where
     A: Clone + 'a,
     B: Clone + 'a,
-    F: Fn(A) -> B,
+    F: Fn(A) -> B + 'a,
 {
     let x = f(t.value());
-    let xs = t.children.into_iter().map(|c| map(f.clone(), c)).collect();
-    Tree::new(x, xs)
+    let xs = lazy!(t
+        .children()
+        .into_iter()
+        .map(|c| map(f.clone(), c))
+        .collect());
+    Tree::from_lazy(x, xs)
+}
+
+/// Combine two trees so that either side can shrink at any point, as `zipTreeT` does in Haskell
+/// hedgehog. Unlike `bind`, shrinking the left value never fixes the right one in place, so both
+/// keep shrinking towards their own minimum.
+pub fn zip<'a, A, B>(l: Tree<'a, A>, r: Tree<'a, B>) -> Tree<'a, (A, B)>
+where
+    A: Clone + 'a,
+    B: Clone + 'a,
+{
+    let x = (l.value(), r.value());
+    let xs = lazy!({
+        let mut xs: Vec<Tree<'a, (A, B)>> = l
+            .children()
+            .into_iter()
+            .map(|l1| zip(l1, r.clone()))
+            .collect();
+        xs.extend(r.children().into_iter().map(|r1| zip(l.clone(), r1)));
+        xs
+    });
+    Tree::from_lazy(x, xs)
+}
+
+/// Combine a list of trees the way `Tree.interleave` does in Haskell hedgehog: first try dropping
+/// runs of elements, halving the run each time, then try shrinking any one element in place.
+pub fn interleave<'a, A>(ts: Vec<Tree<'a, A>>) -> Tree<'a, Vec<A>>
+where
+    A: Clone + 'a,
+{
+    let x = ts.iter().map(|t| t.value()).collect();
+    let xs = lazy!({
+        let n = ts.len();
+        let mut xs = Vec::new();
+        for k in shrink::halves(n) {
+            for i in (0..=n - k).step_by(k) {
+                let mut ys = ts[..i].to_vec();
+                ys.extend_from_slice(&ts[i + k..]);
+                xs.push(interleave(ys));
+            }
+        }
+        for (i, t) in ts.iter().enumerate() {
+            for t1 in t.children() {
+                let mut ys = ts.clone();
+                ys[i] = t1;
+                xs.push(interleave(ys));
+            }
+        }
+        xs
+    });
+    Tree::from_lazy(x, xs)
 }
 
 // should be: shift hd other = zipWith (++) (hd : repeat other)
@@ -228,7 +296,7 @@ fn shift(head: &str, other: &str, lines: Vec<String>) -> Vec<String> {
 
 fn render_forest_lines<'a, A>(limit: i16, forest: &[Tree<'a, A>]) -> Vec<String>
 where
-    A: Debug + Clone,
+    A: Debug + Clone + 'a,
 {
     if limit <= 0 {
         return vec!["...".to_owned()];
@@ -237,17 +305,17 @@ where
     match forest {
         [] => vec![],
         [x] => {
-            let s = render_tree_lines(limit - 1, x.as_ref());
+            let s = render_tree_lines(limit - 1, x);
             shift(" └╼", "   ", s)
         }
         xs0 => {
             let (x, xs) = xs0.split_at(1);
-            let s0 = render_tree_lines(limit - 1, x.first().unwrap().as_ref());
+            let s0 = render_tree_lines(limit - 1, x.first().unwrap());
             let ss = render_forest_lines(limit, xs);
 
             let mut s = shift(" ├╼", " │ ", s0);
 
-            s.extend(ss.into_iter());
+            s.extend(ss);
             s
         }
     }
@@ -255,9 +323,9 @@ where
 
 fn render_tree_lines<'a, A>(limit: i16, x: &Tree<'a, A>) -> Vec<String>
 where
-    A: Debug + Clone,
+    A: Debug + Clone + 'a,
 {
-    let mut children: Vec<String> = render_forest_lines(limit, &x.children);
+    let mut children: Vec<String> = render_forest_lines(limit, &x.children());
     let node = format!(" {:?}", x.value());
 
     children.insert(0, node);
@@ -266,7 +334,7 @@ where
 
 impl<'a, A> Display for Tree<'a, A>
 where
-    A: Copy + Debug,
+    A: Copy + Debug + 'a,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error>
     where
@@ -285,6 +353,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use shrink;
+    use std::cell::Cell;
 
     #[test]
     fn rose_trees_hold_lazy_values() {
@@ -294,4 +364,61 @@ mod tests {
         tree.value();
         assert_eq!(tree.value(), n);
     }
+
+    #[test]
+    fn children_are_computed_on_demand_and_memoised() {
+        let calls = Rc::new(Cell::new(0));
+        let c = calls.clone();
+        let shrink = Rc::new(move |x: u64| {
+            c.set(c.get() + 1);
+            shrink::towards(0, x)
+        });
+        let t = unfold(Rc::new(|x: u64| x), shrink, u64::MAX);
+        assert_eq!(calls.get(), 0);
+
+        let xs = t.children();
+        assert_eq!(xs.first().map(|x| x.value()), Some(0));
+        assert_eq!(calls.get(), 1);
+
+        t.clone().children();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn map_and_bind_preserve_lazy_children() {
+        let shrink = Rc::new(|x: u64| shrink::towards(0, x));
+        let t = unfold(Rc::new(|x: u64| x), shrink, 1 << 40);
+        let doubled = map(Rc::new(|x: u64| x * 2), t.clone());
+        assert_eq!(doubled.value(), 1 << 41);
+        assert_eq!(doubled.children()[0].value(), 0);
+
+        let bound = bind(t, Rc::new(|x: u64| Tree::singleton(x + 1)));
+        assert_eq!(bound.value(), (1 << 40) + 1);
+        assert_eq!(bound.children()[0].value(), 1);
+    }
+
+    fn values<'a, A: Clone + 'a>(xs: Vec<Tree<'a, A>>) -> Vec<A> {
+        xs.iter().map(|x| x.value()).collect()
+    }
+
+    #[test]
+    fn zip_shrinks_either_side() {
+        let towards_zero = |x: u8| unfold(Rc::new(|x| x), Rc::new(|x| shrink::towards(0, x)), x);
+        let t = zip(towards_zero(2), towards_zero(3));
+        assert_eq!(t.value(), (2, 3));
+        assert_eq!(values(t.children()), vec![(0, 3), (1, 3), (2, 0), (2, 2)]);
+        // After the right side shrinks the left one can still shrink.
+        assert_eq!(values(t.children()[2].children()), vec![(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn interleave_drops_runs_then_shrinks_elements() {
+        let ts = vec![Tree::singleton(1), Tree::new(2, vec![Tree::singleton(0)])];
+        let t = interleave(ts);
+        assert_eq!(t.value(), vec![1, 2]);
+        assert_eq!(
+            values(t.children()),
+            vec![vec![], vec![2], vec![1], vec![1, 0]]
+        );
+    }
 }
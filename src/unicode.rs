@@ -0,0 +1,3168 @@
+//! Character tables from the Unicode Character Database, version 14.0.0, embedded so that the
+//! character generators in `gen` work offline. Every table is a sorted list of disjoint, inclusive
+//! ranges of characters.
+//!
+//! Everything down to `BLOCKS` is generated by `scripts/unicode_tables.py` from `UnicodeData.txt`,
+//! `Scripts.txt` and `Blocks.txt` of <https://www.unicode.org/Public/14.0.0/ucd/>. Regenerate
+//! those tables rather than edit them when moving to a newer version of Unicode. `INTERESTING` is
+//! picked by hand.
+
+/// Letters, general categories `Lu`, `Ll`, `Lt`, `Lm` and `Lo`.
+pub const LETTER: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'),
+    ('\u{61}', '\u{7a}'),
+    ('\u{aa}', '\u{aa}'),
+    ('\u{b5}', '\u{b5}'),
+    ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'),
+    ('\u{d8}', '\u{f6}'),
+    ('\u{f8}', '\u{2c1}'),
+    ('\u{2c6}', '\u{2d1}'),
+    ('\u{2e0}', '\u{2e4}'),
+    ('\u{2ec}', '\u{2ec}'),
+    ('\u{2ee}', '\u{2ee}'),
+    ('\u{370}', '\u{374}'),
+    ('\u{376}', '\u{377}'),
+    ('\u{37a}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3f5}'),
+    ('\u{3f7}', '\u{481}'),
+    ('\u{48a}', '\u{52f}'),
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{559}'),
+    ('\u{560}', '\u{588}'),
+    ('\u{5d0}', '\u{5ea}'),
+    ('\u{5ef}', '\u{5f2}'),
+    ('\u{620}', '\u{64a}'),
+    ('\u{66e}', '\u{66f}'),
+    ('\u{671}', '\u{6d3}'),
+    ('\u{6d5}', '\u{6d5}'),
+    ('\u{6e5}', '\u{6e6}'),
+    ('\u{6ee}', '\u{6ef}'),
+    ('\u{6fa}', '\u{6fc}'),
+    ('\u{6ff}', '\u{6ff}'),
+    ('\u{710}', '\u{710}'),
+    ('\u{712}', '\u{72f}'),
+    ('\u{74d}', '\u{7a5}'),
+    ('\u{7b1}', '\u{7b1}'),
+    ('\u{7ca}', '\u{7ea}'),
+    ('\u{7f4}', '\u{7f5}'),
+    ('\u{7fa}', '\u{7fa}'),
+    ('\u{800}', '\u{815}'),
+    ('\u{81a}', '\u{81a}'),
+    ('\u{824}', '\u{824}'),
+    ('\u{828}', '\u{828}'),
+    ('\u{840}', '\u{858}'),
+    ('\u{860}', '\u{86a}'),
+    ('\u{870}', '\u{887}'),
+    ('\u{889}', '\u{88e}'),
+    ('\u{8a0}', '\u{8c9}'),
+    ('\u{904}', '\u{939}'),
+    ('\u{93d}', '\u{93d}'),
+    ('\u{950}', '\u{950}'),
+    ('\u{958}', '\u{961}'),
+    ('\u{971}', '\u{980}'),
+    ('\u{985}', '\u{98c}'),
+    ('\u{98f}', '\u{990}'),
+    ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'),
+    ('\u{9b2}', '\u{9b2}'),
+    ('\u{9b6}', '\u{9b9}'),
+    ('\u{9bd}', '\u{9bd}'),
+    ('\u{9ce}', '\u{9ce}'),
+    ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e1}'),
+    ('\u{9f0}', '\u{9f1}'),
+    ('\u{9fc}', '\u{9fc}'),
+    ('\u{a05}', '\u{a0a}'),
+    ('\u{a0f}', '\u{a10}'),
+    ('\u{a13}', '\u{a28}'),
+    ('\u{a2a}', '\u{a30}'),
+    ('\u{a32}', '\u{a33}'),
+    ('\u{a35}', '\u{a36}'),
+    ('\u{a38}', '\u{a39}'),
+    ('\u{a59}', '\u{a5c}'),
+    ('\u{a5e}', '\u{a5e}'),
+    ('\u{a72}', '\u{a74}'),
+    ('\u{a85}', '\u{a8d}'),
+    ('\u{a8f}', '\u{a91}'),
+    ('\u{a93}', '\u{aa8}'),
+    ('\u{aaa}', '\u{ab0}'),
+    ('\u{ab2}', '\u{ab3}'),
+    ('\u{ab5}', '\u{ab9}'),
+    ('\u{abd}', '\u{abd}'),
+    ('\u{ad0}', '\u{ad0}'),
+    ('\u{ae0}', '\u{ae1}'),
+    ('\u{af9}', '\u{af9}'),
+    ('\u{b05}', '\u{b0c}'),
+    ('\u{b0f}', '\u{b10}'),
+    ('\u{b13}', '\u{b28}'),
+    ('\u{b2a}', '\u{b30}'),
+    ('\u{b32}', '\u{b33}'),
+    ('\u{b35}', '\u{b39}'),
+    ('\u{b3d}', '\u{b3d}'),
+    ('\u{b5c}', '\u{b5d}'),
+    ('\u{b5f}', '\u{b61}'),
+    ('\u{b71}', '\u{b71}'),
+    ('\u{b83}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'),
+    ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'),
+    ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'),
+    ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'),
+    ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'),
+    ('\u{bd0}', '\u{bd0}'),
+    ('\u{c05}', '\u{c0c}'),
+    ('\u{c0e}', '\u{c10}'),
+    ('\u{c12}', '\u{c28}'),
+    ('\u{c2a}', '\u{c39}'),
+    ('\u{c3d}', '\u{c3d}'),
+    ('\u{c58}', '\u{c5a}'),
+    ('\u{c5d}', '\u{c5d}'),
+    ('\u{c60}', '\u{c61}'),
+    ('\u{c80}', '\u{c80}'),
+    ('\u{c85}', '\u{c8c}'),
+    ('\u{c8e}', '\u{c90}'),
+    ('\u{c92}', '\u{ca8}'),
+    ('\u{caa}', '\u{cb3}'),
+    ('\u{cb5}', '\u{cb9}'),
+    ('\u{cbd}', '\u{cbd}'),
+    ('\u{cdd}', '\u{cde}'),
+    ('\u{ce0}', '\u{ce1}'),
+    ('\u{cf1}', '\u{cf2}'),
+    ('\u{d04}', '\u{d0c}'),
+    ('\u{d0e}', '\u{d10}'),
+    ('\u{d12}', '\u{d3a}'),
+    ('\u{d3d}', '\u{d3d}'),
+    ('\u{d4e}', '\u{d4e}'),
+    ('\u{d54}', '\u{d56}'),
+    ('\u{d5f}', '\u{d61}'),
+    ('\u{d7a}', '\u{d7f}'),
+    ('\u{d85}', '\u{d96}'),
+    ('\u{d9a}', '\u{db1}'),
+    ('\u{db3}', '\u{dbb}'),
+    ('\u{dbd}', '\u{dbd}'),
+    ('\u{dc0}', '\u{dc6}'),
+    ('\u{e01}', '\u{e30}'),
+    ('\u{e32}', '\u{e33}'),
+    ('\u{e40}', '\u{e46}'),
+    ('\u{e81}', '\u{e82}'),
+    ('\u{e84}', '\u{e84}'),
+    ('\u{e86}', '\u{e8a}'),
+    ('\u{e8c}', '\u{ea3}'),
+    ('\u{ea5}', '\u{ea5}'),
+    ('\u{ea7}', '\u{eb0}'),
+    ('\u{eb2}', '\u{eb3}'),
+    ('\u{ebd}', '\u{ebd}'),
+    ('\u{ec0}', '\u{ec4}'),
+    ('\u{ec6}', '\u{ec6}'),
+    ('\u{edc}', '\u{edf}'),
+    ('\u{f00}', '\u{f00}'),
+    ('\u{f40}', '\u{f47}'),
+    ('\u{f49}', '\u{f6c}'),
+    ('\u{f88}', '\u{f8c}'),
+    ('\u{1000}', '\u{102a}'),
+    ('\u{103f}', '\u{103f}'),
+    ('\u{1050}', '\u{1055}'),
+    ('\u{105a}', '\u{105d}'),
+    ('\u{1061}', '\u{1061}'),
+    ('\u{1065}', '\u{1066}'),
+    ('\u{106e}', '\u{1070}'),
+    ('\u{1075}', '\u{1081}'),
+    ('\u{108e}', '\u{108e}'),
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{1380}', '\u{138f}'),
+    ('\u{13a0}', '\u{13f5}'),
+    ('\u{13f8}', '\u{13fd}'),
+    ('\u{1401}', '\u{166c}'),
+    ('\u{166f}', '\u{167f}'),
+    ('\u{1681}', '\u{169a}'),
+    ('\u{16a0}', '\u{16ea}'),
+    ('\u{16f1}', '\u{16f8}'),
+    ('\u{1700}', '\u{1711}'),
+    ('\u{171f}', '\u{1731}'),
+    ('\u{1740}', '\u{1751}'),
+    ('\u{1760}', '\u{176c}'),
+    ('\u{176e}', '\u{1770}'),
+    ('\u{1780}', '\u{17b3}'),
+    ('\u{17d7}', '\u{17d7}'),
+    ('\u{17dc}', '\u{17dc}'),
+    ('\u{1820}', '\u{1878}'),
+    ('\u{1880}', '\u{1884}'),
+    ('\u{1887}', '\u{18a8}'),
+    ('\u{18aa}', '\u{18aa}'),
+    ('\u{18b0}', '\u{18f5}'),
+    ('\u{1900}', '\u{191e}'),
+    ('\u{1950}', '\u{196d}'),
+    ('\u{1970}', '\u{1974}'),
+    ('\u{1980}', '\u{19ab}'),
+    ('\u{19b0}', '\u{19c9}'),
+    ('\u{1a00}', '\u{1a16}'),
+    ('\u{1a20}', '\u{1a54}'),
+    ('\u{1aa7}', '\u{1aa7}'),
+    ('\u{1b05}', '\u{1b33}'),
+    ('\u{1b45}', '\u{1b4c}'),
+    ('\u{1b83}', '\u{1ba0}'),
+    ('\u{1bae}', '\u{1baf}'),
+    ('\u{1bba}', '\u{1be5}'),
+    ('\u{1c00}', '\u{1c23}'),
+    ('\u{1c4d}', '\u{1c4f}'),
+    ('\u{1c5a}', '\u{1c7d}'),
+    ('\u{1c80}', '\u{1c88}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'),
+    ('\u{1cf5}', '\u{1cf6}'),
+    ('\u{1cfa}', '\u{1cfa}'),
+    ('\u{1d00}', '\u{1dbf}'),
+    ('\u{1e00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc2}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fcc}'),
+    ('\u{1fd0}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fe0}', '\u{1fec}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffc}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{2102}', '\u{2102}'),
+    ('\u{2107}', '\u{2107}'),
+    ('\u{210a}', '\u{2113}'),
+    ('\u{2115}', '\u{2115}'),
+    ('\u{2119}', '\u{211d}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212a}', '\u{212d}'),
+    ('\u{212f}', '\u{2139}'),
+    ('\u{213c}', '\u{213f}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2183}', '\u{2184}'),
+    ('\u{2c00}', '\u{2ce4}'),
+    ('\u{2ceb}', '\u{2cee}'),
+    ('\u{2cf2}', '\u{2cf3}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+    ('\u{2d30}', '\u{2d67}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2d80}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{2e2f}', '\u{2e2f}'),
+    ('\u{3005}', '\u{3006}'),
+    ('\u{3031}', '\u{3035}'),
+    ('\u{303b}', '\u{303c}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fc}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{31a0}', '\u{31bf}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a4d0}', '\u{a4fd}'),
+    ('\u{a500}', '\u{a60c}'),
+    ('\u{a610}', '\u{a61f}'),
+    ('\u{a62a}', '\u{a62b}'),
+    ('\u{a640}', '\u{a66e}'),
+    ('\u{a67f}', '\u{a69d}'),
+    ('\u{a6a0}', '\u{a6e5}'),
+    ('\u{a717}', '\u{a71f}'),
+    ('\u{a722}', '\u{a788}'),
+    ('\u{a78b}', '\u{a7ca}'),
+    ('\u{a7d0}', '\u{a7d1}'),
+    ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'),
+    ('\u{a7f2}', '\u{a801}'),
+    ('\u{a803}', '\u{a805}'),
+    ('\u{a807}', '\u{a80a}'),
+    ('\u{a80c}', '\u{a822}'),
+    ('\u{a840}', '\u{a873}'),
+    ('\u{a882}', '\u{a8b3}'),
+    ('\u{a8f2}', '\u{a8f7}'),
+    ('\u{a8fb}', '\u{a8fb}'),
+    ('\u{a8fd}', '\u{a8fe}'),
+    ('\u{a90a}', '\u{a925}'),
+    ('\u{a930}', '\u{a946}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{a984}', '\u{a9b2}'),
+    ('\u{a9cf}', '\u{a9cf}'),
+    ('\u{a9e0}', '\u{a9e4}'),
+    ('\u{a9e6}', '\u{a9ef}'),
+    ('\u{a9fa}', '\u{a9fe}'),
+    ('\u{aa00}', '\u{aa28}'),
+    ('\u{aa40}', '\u{aa42}'),
+    ('\u{aa44}', '\u{aa4b}'),
+    ('\u{aa60}', '\u{aa76}'),
+    ('\u{aa7a}', '\u{aa7a}'),
+    ('\u{aa7e}', '\u{aaaf}'),
+    ('\u{aab1}', '\u{aab1}'),
+    ('\u{aab5}', '\u{aab6}'),
+    ('\u{aab9}', '\u{aabd}'),
+    ('\u{aac0}', '\u{aac0}'),
+    ('\u{aac2}', '\u{aac2}'),
+    ('\u{aadb}', '\u{aadd}'),
+    ('\u{aae0}', '\u{aaea}'),
+    ('\u{aaf2}', '\u{aaf4}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab69}'),
+    ('\u{ab70}', '\u{abe2}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb28}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdfb}'),
+    ('\u{fe70}', '\u{fe74}'),
+    ('\u{fe76}', '\u{fefc}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{ff66}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{10000}', '\u{1000b}'),
+    ('\u{1000d}', '\u{10026}'),
+    ('\u{10028}', '\u{1003a}'),
+    ('\u{1003c}', '\u{1003d}'),
+    ('\u{1003f}', '\u{1004d}'),
+    ('\u{10050}', '\u{1005d}'),
+    ('\u{10080}', '\u{100fa}'),
+    ('\u{10280}', '\u{1029c}'),
+    ('\u{102a0}', '\u{102d0}'),
+    ('\u{10300}', '\u{1031f}'),
+    ('\u{1032d}', '\u{10340}'),
+    ('\u{10342}', '\u{10349}'),
+    ('\u{10350}', '\u{10375}'),
+    ('\u{10380}', '\u{1039d}'),
+    ('\u{103a0}', '\u{103c3}'),
+    ('\u{103c8}', '\u{103cf}'),
+    ('\u{10400}', '\u{1049d}'),
+    ('\u{104b0}', '\u{104d3}'),
+    ('\u{104d8}', '\u{104fb}'),
+    ('\u{10500}', '\u{10527}'),
+    ('\u{10530}', '\u{10563}'),
+    ('\u{10570}', '\u{1057a}'),
+    ('\u{1057c}', '\u{1058a}'),
+    ('\u{1058c}', '\u{10592}'),
+    ('\u{10594}', '\u{10595}'),
+    ('\u{10597}', '\u{105a1}'),
+    ('\u{105a3}', '\u{105b1}'),
+    ('\u{105b3}', '\u{105b9}'),
+    ('\u{105bb}', '\u{105bc}'),
+    ('\u{10600}', '\u{10736}'),
+    ('\u{10740}', '\u{10755}'),
+    ('\u{10760}', '\u{10767}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{10800}', '\u{10805}'),
+    ('\u{10808}', '\u{10808}'),
+    ('\u{1080a}', '\u{10835}'),
+    ('\u{10837}', '\u{10838}'),
+    ('\u{1083c}', '\u{1083c}'),
+    ('\u{1083f}', '\u{10855}'),
+    ('\u{10860}', '\u{10876}'),
+    ('\u{10880}', '\u{1089e}'),
+    ('\u{108e0}', '\u{108f2}'),
+    ('\u{108f4}', '\u{108f5}'),
+    ('\u{10900}', '\u{10915}'),
+    ('\u{10920}', '\u{10939}'),
+    ('\u{10980}', '\u{109b7}'),
+    ('\u{109be}', '\u{109bf}'),
+    ('\u{10a00}', '\u{10a00}'),
+    ('\u{10a10}', '\u{10a13}'),
+    ('\u{10a15}', '\u{10a17}'),
+    ('\u{10a19}', '\u{10a35}'),
+    ('\u{10a60}', '\u{10a7c}'),
+    ('\u{10a80}', '\u{10a9c}'),
+    ('\u{10ac0}', '\u{10ac7}'),
+    ('\u{10ac9}', '\u{10ae4}'),
+    ('\u{10b00}', '\u{10b35}'),
+    ('\u{10b40}', '\u{10b55}'),
+    ('\u{10b60}', '\u{10b72}'),
+    ('\u{10b80}', '\u{10b91}'),
+    ('\u{10c00}', '\u{10c48}'),
+    ('\u{10c80}', '\u{10cb2}'),
+    ('\u{10cc0}', '\u{10cf2}'),
+    ('\u{10d00}', '\u{10d23}'),
+    ('\u{10e80}', '\u{10ea9}'),
+    ('\u{10eb0}', '\u{10eb1}'),
+    ('\u{10f00}', '\u{10f1c}'),
+    ('\u{10f27}', '\u{10f27}'),
+    ('\u{10f30}', '\u{10f45}'),
+    ('\u{10f70}', '\u{10f81}'),
+    ('\u{10fb0}', '\u{10fc4}'),
+    ('\u{10fe0}', '\u{10ff6}'),
+    ('\u{11003}', '\u{11037}'),
+    ('\u{11071}', '\u{11072}'),
+    ('\u{11075}', '\u{11075}'),
+    ('\u{11083}', '\u{110af}'),
+    ('\u{110d0}', '\u{110e8}'),
+    ('\u{11103}', '\u{11126}'),
+    ('\u{11144}', '\u{11144}'),
+    ('\u{11147}', '\u{11147}'),
+    ('\u{11150}', '\u{11172}'),
+    ('\u{11176}', '\u{11176}'),
+    ('\u{11183}', '\u{111b2}'),
+    ('\u{111c1}', '\u{111c4}'),
+    ('\u{111da}', '\u{111da}'),
+    ('\u{111dc}', '\u{111dc}'),
+    ('\u{11200}', '\u{11211}'),
+    ('\u{11213}', '\u{1122b}'),
+    ('\u{11280}', '\u{11286}'),
+    ('\u{11288}', '\u{11288}'),
+    ('\u{1128a}', '\u{1128d}'),
+    ('\u{1128f}', '\u{1129d}'),
+    ('\u{1129f}', '\u{112a8}'),
+    ('\u{112b0}', '\u{112de}'),
+    ('\u{11305}', '\u{1130c}'),
+    ('\u{1130f}', '\u{11310}'),
+    ('\u{11313}', '\u{11328}'),
+    ('\u{1132a}', '\u{11330}'),
+    ('\u{11332}', '\u{11333}'),
+    ('\u{11335}', '\u{11339}'),
+    ('\u{1133d}', '\u{1133d}'),
+    ('\u{11350}', '\u{11350}'),
+    ('\u{1135d}', '\u{11361}'),
+    ('\u{11400}', '\u{11434}'),
+    ('\u{11447}', '\u{1144a}'),
+    ('\u{1145f}', '\u{11461}'),
+    ('\u{11480}', '\u{114af}'),
+    ('\u{114c4}', '\u{114c5}'),
+    ('\u{114c7}', '\u{114c7}'),
+    ('\u{11580}', '\u{115ae}'),
+    ('\u{115d8}', '\u{115db}'),
+    ('\u{11600}', '\u{1162f}'),
+    ('\u{11644}', '\u{11644}'),
+    ('\u{11680}', '\u{116aa}'),
+    ('\u{116b8}', '\u{116b8}'),
+    ('\u{11700}', '\u{1171a}'),
+    ('\u{11740}', '\u{11746}'),
+    ('\u{11800}', '\u{1182b}'),
+    ('\u{118a0}', '\u{118df}'),
+    ('\u{118ff}', '\u{11906}'),
+    ('\u{11909}', '\u{11909}'),
+    ('\u{1190c}', '\u{11913}'),
+    ('\u{11915}', '\u{11916}'),
+    ('\u{11918}', '\u{1192f}'),
+    ('\u{1193f}', '\u{1193f}'),
+    ('\u{11941}', '\u{11941}'),
+    ('\u{119a0}', '\u{119a7}'),
+    ('\u{119aa}', '\u{119d0}'),
+    ('\u{119e1}', '\u{119e1}'),
+    ('\u{119e3}', '\u{119e3}'),
+    ('\u{11a00}', '\u{11a00}'),
+    ('\u{11a0b}', '\u{11a32}'),
+    ('\u{11a3a}', '\u{11a3a}'),
+    ('\u{11a50}', '\u{11a50}'),
+    ('\u{11a5c}', '\u{11a89}'),
+    ('\u{11a9d}', '\u{11a9d}'),
+    ('\u{11ab0}', '\u{11af8}'),
+    ('\u{11c00}', '\u{11c08}'),
+    ('\u{11c0a}', '\u{11c2e}'),
+    ('\u{11c40}', '\u{11c40}'),
+    ('\u{11c72}', '\u{11c8f}'),
+    ('\u{11d00}', '\u{11d06}'),
+    ('\u{11d08}', '\u{11d09}'),
+    ('\u{11d0b}', '\u{11d30}'),
+    ('\u{11d46}', '\u{11d46}'),
+    ('\u{11d60}', '\u{11d65}'),
+    ('\u{11d67}', '\u{11d68}'),
+    ('\u{11d6a}', '\u{11d89}'),
+    ('\u{11d98}', '\u{11d98}'),
+    ('\u{11ee0}', '\u{11ef2}'),
+    ('\u{11fb0}', '\u{11fb0}'),
+    ('\u{12000}', '\u{12399}'),
+    ('\u{12480}', '\u{12543}'),
+    ('\u{12f90}', '\u{12ff0}'),
+    ('\u{13000}', '\u{1342e}'),
+    ('\u{14400}', '\u{14646}'),
+    ('\u{16800}', '\u{16a38}'),
+    ('\u{16a40}', '\u{16a5e}'),
+    ('\u{16a70}', '\u{16abe}'),
+    ('\u{16ad0}', '\u{16aed}'),
+    ('\u{16b00}', '\u{16b2f}'),
+    ('\u{16b40}', '\u{16b43}'),
+    ('\u{16b63}', '\u{16b77}'),
+    ('\u{16b7d}', '\u{16b8f}'),
+    ('\u{16e40}', '\u{16e7f}'),
+    ('\u{16f00}', '\u{16f4a}'),
+    ('\u{16f50}', '\u{16f50}'),
+    ('\u{16f93}', '\u{16f9f}'),
+    ('\u{16fe0}', '\u{16fe1}'),
+    ('\u{16fe3}', '\u{16fe3}'),
+    ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'),
+    ('\u{18d00}', '\u{18d08}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1bc00}', '\u{1bc6a}'),
+    ('\u{1bc70}', '\u{1bc7c}'),
+    ('\u{1bc80}', '\u{1bc88}'),
+    ('\u{1bc90}', '\u{1bc99}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d6c0}'),
+    ('\u{1d6c2}', '\u{1d6da}'),
+    ('\u{1d6dc}', '\u{1d6fa}'),
+    ('\u{1d6fc}', '\u{1d714}'),
+    ('\u{1d716}', '\u{1d734}'),
+    ('\u{1d736}', '\u{1d74e}'),
+    ('\u{1d750}', '\u{1d76e}'),
+    ('\u{1d770}', '\u{1d788}'),
+    ('\u{1d78a}', '\u{1d7a8}'),
+    ('\u{1d7aa}', '\u{1d7c2}'),
+    ('\u{1d7c4}', '\u{1d7cb}'),
+    ('\u{1df00}', '\u{1df1e}'),
+    ('\u{1e100}', '\u{1e12c}'),
+    ('\u{1e137}', '\u{1e13d}'),
+    ('\u{1e14e}', '\u{1e14e}'),
+    ('\u{1e290}', '\u{1e2ad}'),
+    ('\u{1e2c0}', '\u{1e2eb}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+    ('\u{1e800}', '\u{1e8c4}'),
+    ('\u{1e900}', '\u{1e943}'),
+    ('\u{1e94b}', '\u{1e94b}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b738}'),
+    ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+];
+
+/// Combining marks, general categories `Mn`, `Mc` and `Me`.
+pub const MARK: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'),
+    ('\u{483}', '\u{489}'),
+    ('\u{591}', '\u{5bd}'),
+    ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'),
+    ('\u{5c4}', '\u{5c5}'),
+    ('\u{5c7}', '\u{5c7}'),
+    ('\u{610}', '\u{61a}'),
+    ('\u{64b}', '\u{65f}'),
+    ('\u{670}', '\u{670}'),
+    ('\u{6d6}', '\u{6dc}'),
+    ('\u{6df}', '\u{6e4}'),
+    ('\u{6e7}', '\u{6e8}'),
+    ('\u{6ea}', '\u{6ed}'),
+    ('\u{711}', '\u{711}'),
+    ('\u{730}', '\u{74a}'),
+    ('\u{7a6}', '\u{7b0}'),
+    ('\u{7eb}', '\u{7f3}'),
+    ('\u{7fd}', '\u{7fd}'),
+    ('\u{816}', '\u{819}'),
+    ('\u{81b}', '\u{823}'),
+    ('\u{825}', '\u{827}'),
+    ('\u{829}', '\u{82d}'),
+    ('\u{859}', '\u{85b}'),
+    ('\u{898}', '\u{89f}'),
+    ('\u{8ca}', '\u{8e1}'),
+    ('\u{8e3}', '\u{903}'),
+    ('\u{93a}', '\u{93c}'),
+    ('\u{93e}', '\u{94f}'),
+    ('\u{951}', '\u{957}'),
+    ('\u{962}', '\u{963}'),
+    ('\u{981}', '\u{983}'),
+    ('\u{9bc}', '\u{9bc}'),
+    ('\u{9be}', '\u{9c4}'),
+    ('\u{9c7}', '\u{9c8}'),
+    ('\u{9cb}', '\u{9cd}'),
+    ('\u{9d7}', '\u{9d7}'),
+    ('\u{9e2}', '\u{9e3}'),
+    ('\u{9fe}', '\u{9fe}'),
+    ('\u{a01}', '\u{a03}'),
+    ('\u{a3c}', '\u{a3c}'),
+    ('\u{a3e}', '\u{a42}'),
+    ('\u{a47}', '\u{a48}'),
+    ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'),
+    ('\u{a70}', '\u{a71}'),
+    ('\u{a75}', '\u{a75}'),
+    ('\u{a81}', '\u{a83}'),
+    ('\u{abc}', '\u{abc}'),
+    ('\u{abe}', '\u{ac5}'),
+    ('\u{ac7}', '\u{ac9}'),
+    ('\u{acb}', '\u{acd}'),
+    ('\u{ae2}', '\u{ae3}'),
+    ('\u{afa}', '\u{aff}'),
+    ('\u{b01}', '\u{b03}'),
+    ('\u{b3c}', '\u{b3c}'),
+    ('\u{b3e}', '\u{b44}'),
+    ('\u{b47}', '\u{b48}'),
+    ('\u{b4b}', '\u{b4d}'),
+    ('\u{b55}', '\u{b57}'),
+    ('\u{b62}', '\u{b63}'),
+    ('\u{b82}', '\u{b82}'),
+    ('\u{bbe}', '\u{bc2}'),
+    ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'),
+    ('\u{bd7}', '\u{bd7}'),
+    ('\u{c00}', '\u{c04}'),
+    ('\u{c3c}', '\u{c3c}'),
+    ('\u{c3e}', '\u{c44}'),
+    ('\u{c46}', '\u{c48}'),
+    ('\u{c4a}', '\u{c4d}'),
+    ('\u{c55}', '\u{c56}'),
+    ('\u{c62}', '\u{c63}'),
+    ('\u{c81}', '\u{c83}'),
+    ('\u{cbc}', '\u{cbc}'),
+    ('\u{cbe}', '\u{cc4}'),
+    ('\u{cc6}', '\u{cc8}'),
+    ('\u{cca}', '\u{ccd}'),
+    ('\u{cd5}', '\u{cd6}'),
+    ('\u{ce2}', '\u{ce3}'),
+    ('\u{d00}', '\u{d03}'),
+    ('\u{d3b}', '\u{d3c}'),
+    ('\u{d3e}', '\u{d44}'),
+    ('\u{d46}', '\u{d48}'),
+    ('\u{d4a}', '\u{d4d}'),
+    ('\u{d57}', '\u{d57}'),
+    ('\u{d62}', '\u{d63}'),
+    ('\u{d81}', '\u{d83}'),
+    ('\u{dca}', '\u{dca}'),
+    ('\u{dcf}', '\u{dd4}'),
+    ('\u{dd6}', '\u{dd6}'),
+    ('\u{dd8}', '\u{ddf}'),
+    ('\u{df2}', '\u{df3}'),
+    ('\u{e31}', '\u{e31}'),
+    ('\u{e34}', '\u{e3a}'),
+    ('\u{e47}', '\u{e4e}'),
+    ('\u{eb1}', '\u{eb1}'),
+    ('\u{eb4}', '\u{ebc}'),
+    ('\u{ec8}', '\u{ecd}'),
+    ('\u{f18}', '\u{f19}'),
+    ('\u{f35}', '\u{f35}'),
+    ('\u{f37}', '\u{f37}'),
+    ('\u{f39}', '\u{f39}'),
+    ('\u{f3e}', '\u{f3f}'),
+    ('\u{f71}', '\u{f84}'),
+    ('\u{f86}', '\u{f87}'),
+    ('\u{f8d}', '\u{f97}'),
+    ('\u{f99}', '\u{fbc}'),
+    ('\u{fc6}', '\u{fc6}'),
+    ('\u{102b}', '\u{103e}'),
+    ('\u{1056}', '\u{1059}'),
+    ('\u{105e}', '\u{1060}'),
+    ('\u{1062}', '\u{1064}'),
+    ('\u{1067}', '\u{106d}'),
+    ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{108d}'),
+    ('\u{108f}', '\u{108f}'),
+    ('\u{109a}', '\u{109d}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1712}', '\u{1715}'),
+    ('\u{1732}', '\u{1734}'),
+    ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{17b4}', '\u{17d3}'),
+    ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{180f}', '\u{180f}'),
+    ('\u{1885}', '\u{1886}'),
+    ('\u{18a9}', '\u{18a9}'),
+    ('\u{1920}', '\u{192b}'),
+    ('\u{1930}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a1b}'),
+    ('\u{1a55}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'),
+    ('\u{1ab0}', '\u{1ace}'),
+    ('\u{1b00}', '\u{1b04}'),
+    ('\u{1b34}', '\u{1b44}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1b82}'),
+    ('\u{1ba1}', '\u{1bad}'),
+    ('\u{1be6}', '\u{1bf3}'),
+    ('\u{1c24}', '\u{1c37}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce8}'),
+    ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf7}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{2cef}', '\u{2cf1}'),
+    ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{302a}', '\u{302f}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{a66f}', '\u{a672}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a69e}', '\u{a69f}'),
+    ('\u{a6f0}', '\u{a6f1}'),
+    ('\u{a802}', '\u{a802}'),
+    ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'),
+    ('\u{a823}', '\u{a827}'),
+    ('\u{a82c}', '\u{a82c}'),
+    ('\u{a880}', '\u{a881}'),
+    ('\u{a8b4}', '\u{a8c5}'),
+    ('\u{a8e0}', '\u{a8f1}'),
+    ('\u{a8ff}', '\u{a8ff}'),
+    ('\u{a926}', '\u{a92d}'),
+    ('\u{a947}', '\u{a953}'),
+    ('\u{a980}', '\u{a983}'),
+    ('\u{a9b3}', '\u{a9c0}'),
+    ('\u{a9e5}', '\u{a9e5}'),
+    ('\u{aa29}', '\u{aa36}'),
+    ('\u{aa43}', '\u{aa43}'),
+    ('\u{aa4c}', '\u{aa4d}'),
+    ('\u{aa7b}', '\u{aa7d}'),
+    ('\u{aab0}', '\u{aab0}'),
+    ('\u{aab2}', '\u{aab4}'),
+    ('\u{aab7}', '\u{aab8}'),
+    ('\u{aabe}', '\u{aabf}'),
+    ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaeb}', '\u{aaef}'),
+    ('\u{aaf5}', '\u{aaf6}'),
+    ('\u{abe3}', '\u{abea}'),
+    ('\u{abec}', '\u{abed}'),
+    ('\u{fb1e}', '\u{fb1e}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10376}', '\u{1037a}'),
+    ('\u{10a01}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a0f}'),
+    ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10ae5}', '\u{10ae6}'),
+    ('\u{10d24}', '\u{10d27}'),
+    ('\u{10eab}', '\u{10eac}'),
+    ('\u{10f46}', '\u{10f50}'),
+    ('\u{10f82}', '\u{10f85}'),
+    ('\u{11000}', '\u{11002}'),
+    ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'),
+    ('\u{1107f}', '\u{11082}'),
+    ('\u{110b0}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{11134}'),
+    ('\u{11145}', '\u{11146}'),
+    ('\u{11173}', '\u{11173}'),
+    ('\u{11180}', '\u{11182}'),
+    ('\u{111b3}', '\u{111c0}'),
+    ('\u{111c9}', '\u{111cc}'),
+    ('\u{111ce}', '\u{111cf}'),
+    ('\u{1122c}', '\u{11237}'),
+    ('\u{1123e}', '\u{1123e}'),
+    ('\u{112df}', '\u{112ea}'),
+    ('\u{11300}', '\u{11303}'),
+    ('\u{1133b}', '\u{1133c}'),
+    ('\u{1133e}', '\u{11344}'),
+    ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'),
+    ('\u{11357}', '\u{11357}'),
+    ('\u{11362}', '\u{11363}'),
+    ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11435}', '\u{11446}'),
+    ('\u{1145e}', '\u{1145e}'),
+    ('\u{114b0}', '\u{114c3}'),
+    ('\u{115af}', '\u{115b5}'),
+    ('\u{115b8}', '\u{115c0}'),
+    ('\u{115dc}', '\u{115dd}'),
+    ('\u{11630}', '\u{11640}'),
+    ('\u{116ab}', '\u{116b7}'),
+    ('\u{1171d}', '\u{1172b}'),
+    ('\u{1182c}', '\u{1183a}'),
+    ('\u{11930}', '\u{11935}'),
+    ('\u{11937}', '\u{11938}'),
+    ('\u{1193b}', '\u{1193e}'),
+    ('\u{11940}', '\u{11940}'),
+    ('\u{11942}', '\u{11943}'),
+    ('\u{119d1}', '\u{119d7}'),
+    ('\u{119da}', '\u{119e0}'),
+    ('\u{119e4}', '\u{119e4}'),
+    ('\u{11a01}', '\u{11a0a}'),
+    ('\u{11a33}', '\u{11a39}'),
+    ('\u{11a3b}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'),
+    ('\u{11a51}', '\u{11a5b}'),
+    ('\u{11a8a}', '\u{11a99}'),
+    ('\u{11c2f}', '\u{11c36}'),
+    ('\u{11c38}', '\u{11c3f}'),
+    ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11ca9}', '\u{11cb6}'),
+    ('\u{11d31}', '\u{11d36}'),
+    ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d45}'),
+    ('\u{11d47}', '\u{11d47}'),
+    ('\u{11d8a}', '\u{11d8e}'),
+    ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d93}', '\u{11d97}'),
+    ('\u{11ef3}', '\u{11ef6}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b30}', '\u{16b36}'),
+    ('\u{16f4f}', '\u{16f4f}'),
+    ('\u{16f51}', '\u{16f87}'),
+    ('\u{16f8f}', '\u{16f92}'),
+    ('\u{16fe4}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'),
+    ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'),
+    ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'),
+    ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1e000}', '\u{1e006}'),
+    ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e130}', '\u{1e136}'),
+    ('\u{1e2ae}', '\u{1e2ae}'),
+    ('\u{1e2ec}', '\u{1e2ef}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e944}', '\u{1e94a}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+/// Numbers, general categories `Nd`, `Nl` and `No`.
+pub const NUMBER: &[(char, char)] = &[
+    ('\u{30}', '\u{39}'),
+    ('\u{b2}', '\u{b3}'),
+    ('\u{b9}', '\u{b9}'),
+    ('\u{bc}', '\u{be}'),
+    ('\u{660}', '\u{669}'),
+    ('\u{6f0}', '\u{6f9}'),
+    ('\u{7c0}', '\u{7c9}'),
+    ('\u{966}', '\u{96f}'),
+    ('\u{9e6}', '\u{9ef}'),
+    ('\u{9f4}', '\u{9f9}'),
+    ('\u{a66}', '\u{a6f}'),
+    ('\u{ae6}', '\u{aef}'),
+    ('\u{b66}', '\u{b6f}'),
+    ('\u{b72}', '\u{b77}'),
+    ('\u{be6}', '\u{bf2}'),
+    ('\u{c66}', '\u{c6f}'),
+    ('\u{c78}', '\u{c7e}'),
+    ('\u{ce6}', '\u{cef}'),
+    ('\u{d58}', '\u{d5e}'),
+    ('\u{d66}', '\u{d78}'),
+    ('\u{de6}', '\u{def}'),
+    ('\u{e50}', '\u{e59}'),
+    ('\u{ed0}', '\u{ed9}'),
+    ('\u{f20}', '\u{f33}'),
+    ('\u{1040}', '\u{1049}'),
+    ('\u{1090}', '\u{1099}'),
+    ('\u{1369}', '\u{137c}'),
+    ('\u{16ee}', '\u{16f0}'),
+    ('\u{17e0}', '\u{17e9}'),
+    ('\u{17f0}', '\u{17f9}'),
+    ('\u{1810}', '\u{1819}'),
+    ('\u{1946}', '\u{194f}'),
+    ('\u{19d0}', '\u{19da}'),
+    ('\u{1a80}', '\u{1a89}'),
+    ('\u{1a90}', '\u{1a99}'),
+    ('\u{1b50}', '\u{1b59}'),
+    ('\u{1bb0}', '\u{1bb9}'),
+    ('\u{1c40}', '\u{1c49}'),
+    ('\u{1c50}', '\u{1c59}'),
+    ('\u{2070}', '\u{2070}'),
+    ('\u{2074}', '\u{2079}'),
+    ('\u{2080}', '\u{2089}'),
+    ('\u{2150}', '\u{2182}'),
+    ('\u{2185}', '\u{2189}'),
+    ('\u{2460}', '\u{249b}'),
+    ('\u{24ea}', '\u{24ff}'),
+    ('\u{2776}', '\u{2793}'),
+    ('\u{2cfd}', '\u{2cfd}'),
+    ('\u{3007}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3038}', '\u{303a}'),
+    ('\u{3192}', '\u{3195}'),
+    ('\u{3220}', '\u{3229}'),
+    ('\u{3248}', '\u{324f}'),
+    ('\u{3251}', '\u{325f}'),
+    ('\u{3280}', '\u{3289}'),
+    ('\u{32b1}', '\u{32bf}'),
+    ('\u{a620}', '\u{a629}'),
+    ('\u{a6e6}', '\u{a6ef}'),
+    ('\u{a830}', '\u{a835}'),
+    ('\u{a8d0}', '\u{a8d9}'),
+    ('\u{a900}', '\u{a909}'),
+    ('\u{a9d0}', '\u{a9d9}'),
+    ('\u{a9f0}', '\u{a9f9}'),
+    ('\u{aa50}', '\u{aa59}'),
+    ('\u{abf0}', '\u{abf9}'),
+    ('\u{ff10}', '\u{ff19}'),
+    ('\u{10107}', '\u{10133}'),
+    ('\u{10140}', '\u{10178}'),
+    ('\u{1018a}', '\u{1018b}'),
+    ('\u{102e1}', '\u{102fb}'),
+    ('\u{10320}', '\u{10323}'),
+    ('\u{10341}', '\u{10341}'),
+    ('\u{1034a}', '\u{1034a}'),
+    ('\u{103d1}', '\u{103d5}'),
+    ('\u{104a0}', '\u{104a9}'),
+    ('\u{10858}', '\u{1085f}'),
+    ('\u{10879}', '\u{1087f}'),
+    ('\u{108a7}', '\u{108af}'),
+    ('\u{108fb}', '\u{108ff}'),
+    ('\u{10916}', '\u{1091b}'),
+    ('\u{109bc}', '\u{109bd}'),
+    ('\u{109c0}', '\u{109cf}'),
+    ('\u{109d2}', '\u{109ff}'),
+    ('\u{10a40}', '\u{10a48}'),
+    ('\u{10a7d}', '\u{10a7e}'),
+    ('\u{10a9d}', '\u{10a9f}'),
+    ('\u{10aeb}', '\u{10aef}'),
+    ('\u{10b58}', '\u{10b5f}'),
+    ('\u{10b78}', '\u{10b7f}'),
+    ('\u{10ba9}', '\u{10baf}'),
+    ('\u{10cfa}', '\u{10cff}'),
+    ('\u{10d30}', '\u{10d39}'),
+    ('\u{10e60}', '\u{10e7e}'),
+    ('\u{10f1d}', '\u{10f26}'),
+    ('\u{10f51}', '\u{10f54}'),
+    ('\u{10fc5}', '\u{10fcb}'),
+    ('\u{11052}', '\u{1106f}'),
+    ('\u{110f0}', '\u{110f9}'),
+    ('\u{11136}', '\u{1113f}'),
+    ('\u{111d0}', '\u{111d9}'),
+    ('\u{111e1}', '\u{111f4}'),
+    ('\u{112f0}', '\u{112f9}'),
+    ('\u{11450}', '\u{11459}'),
+    ('\u{114d0}', '\u{114d9}'),
+    ('\u{11650}', '\u{11659}'),
+    ('\u{116c0}', '\u{116c9}'),
+    ('\u{11730}', '\u{1173b}'),
+    ('\u{118e0}', '\u{118f2}'),
+    ('\u{11950}', '\u{11959}'),
+    ('\u{11c50}', '\u{11c6c}'),
+    ('\u{11d50}', '\u{11d59}'),
+    ('\u{11da0}', '\u{11da9}'),
+    ('\u{11fc0}', '\u{11fd4}'),
+    ('\u{12400}', '\u{1246e}'),
+    ('\u{16a60}', '\u{16a69}'),
+    ('\u{16ac0}', '\u{16ac9}'),
+    ('\u{16b50}', '\u{16b59}'),
+    ('\u{16b5b}', '\u{16b61}'),
+    ('\u{16e80}', '\u{16e96}'),
+    ('\u{1d2e0}', '\u{1d2f3}'),
+    ('\u{1d360}', '\u{1d378}'),
+    ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1e140}', '\u{1e149}'),
+    ('\u{1e2f0}', '\u{1e2f9}'),
+    ('\u{1e8c7}', '\u{1e8cf}'),
+    ('\u{1e950}', '\u{1e959}'),
+    ('\u{1ec71}', '\u{1ecab}'),
+    ('\u{1ecad}', '\u{1ecaf}'),
+    ('\u{1ecb1}', '\u{1ecb4}'),
+    ('\u{1ed01}', '\u{1ed2d}'),
+    ('\u{1ed2f}', '\u{1ed3d}'),
+    ('\u{1f100}', '\u{1f10c}'),
+    ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+/// Punctuation, general categories `Pc`, `Pd`, `Ps`, `Pe`, `Pi`, `Pf` and `Po`.
+pub const PUNCTUATION: &[(char, char)] = &[
+    ('\u{21}', '\u{23}'),
+    ('\u{25}', '\u{2a}'),
+    ('\u{2c}', '\u{2f}'),
+    ('\u{3a}', '\u{3b}'),
+    ('\u{3f}', '\u{40}'),
+    ('\u{5b}', '\u{5d}'),
+    ('\u{5f}', '\u{5f}'),
+    ('\u{7b}', '\u{7b}'),
+    ('\u{7d}', '\u{7d}'),
+    ('\u{a1}', '\u{a1}'),
+    ('\u{a7}', '\u{a7}'),
+    ('\u{ab}', '\u{ab}'),
+    ('\u{b6}', '\u{b7}'),
+    ('\u{bb}', '\u{bb}'),
+    ('\u{bf}', '\u{bf}'),
+    ('\u{37e}', '\u{37e}'),
+    ('\u{387}', '\u{387}'),
+    ('\u{55a}', '\u{55f}'),
+    ('\u{589}', '\u{58a}'),
+    ('\u{5be}', '\u{5be}'),
+    ('\u{5c0}', '\u{5c0}'),
+    ('\u{5c3}', '\u{5c3}'),
+    ('\u{5c6}', '\u{5c6}'),
+    ('\u{5f3}', '\u{5f4}'),
+    ('\u{609}', '\u{60a}'),
+    ('\u{60c}', '\u{60d}'),
+    ('\u{61b}', '\u{61b}'),
+    ('\u{61d}', '\u{61f}'),
+    ('\u{66a}', '\u{66d}'),
+    ('\u{6d4}', '\u{6d4}'),
+    ('\u{700}', '\u{70d}'),
+    ('\u{7f7}', '\u{7f9}'),
+    ('\u{830}', '\u{83e}'),
+    ('\u{85e}', '\u{85e}'),
+    ('\u{964}', '\u{965}'),
+    ('\u{970}', '\u{970}'),
+    ('\u{9fd}', '\u{9fd}'),
+    ('\u{a76}', '\u{a76}'),
+    ('\u{af0}', '\u{af0}'),
+    ('\u{c77}', '\u{c77}'),
+    ('\u{c84}', '\u{c84}'),
+    ('\u{df4}', '\u{df4}'),
+    ('\u{e4f}', '\u{e4f}'),
+    ('\u{e5a}', '\u{e5b}'),
+    ('\u{f04}', '\u{f12}'),
+    ('\u{f14}', '\u{f14}'),
+    ('\u{f3a}', '\u{f3d}'),
+    ('\u{f85}', '\u{f85}'),
+    ('\u{fd0}', '\u{fd4}'),
+    ('\u{fd9}', '\u{fda}'),
+    ('\u{104a}', '\u{104f}'),
+    ('\u{10fb}', '\u{10fb}'),
+    ('\u{1360}', '\u{1368}'),
+    ('\u{1400}', '\u{1400}'),
+    ('\u{166e}', '\u{166e}'),
+    ('\u{169b}', '\u{169c}'),
+    ('\u{16eb}', '\u{16ed}'),
+    ('\u{1735}', '\u{1736}'),
+    ('\u{17d4}', '\u{17d6}'),
+    ('\u{17d8}', '\u{17da}'),
+    ('\u{1800}', '\u{180a}'),
+    ('\u{1944}', '\u{1945}'),
+    ('\u{1a1e}', '\u{1a1f}'),
+    ('\u{1aa0}', '\u{1aa6}'),
+    ('\u{1aa8}', '\u{1aad}'),
+    ('\u{1b5a}', '\u{1b60}'),
+    ('\u{1b7d}', '\u{1b7e}'),
+    ('\u{1bfc}', '\u{1bff}'),
+    ('\u{1c3b}', '\u{1c3f}'),
+    ('\u{1c7e}', '\u{1c7f}'),
+    ('\u{1cc0}', '\u{1cc7}'),
+    ('\u{1cd3}', '\u{1cd3}'),
+    ('\u{2010}', '\u{2027}'),
+    ('\u{2030}', '\u{2043}'),
+    ('\u{2045}', '\u{2051}'),
+    ('\u{2053}', '\u{205e}'),
+    ('\u{207d}', '\u{207e}'),
+    ('\u{208d}', '\u{208e}'),
+    ('\u{2308}', '\u{230b}'),
+    ('\u{2329}', '\u{232a}'),
+    ('\u{2768}', '\u{2775}'),
+    ('\u{27c5}', '\u{27c6}'),
+    ('\u{27e6}', '\u{27ef}'),
+    ('\u{2983}', '\u{2998}'),
+    ('\u{29d8}', '\u{29db}'),
+    ('\u{29fc}', '\u{29fd}'),
+    ('\u{2cf9}', '\u{2cfc}'),
+    ('\u{2cfe}', '\u{2cff}'),
+    ('\u{2d70}', '\u{2d70}'),
+    ('\u{2e00}', '\u{2e2e}'),
+    ('\u{2e30}', '\u{2e4f}'),
+    ('\u{2e52}', '\u{2e5d}'),
+    ('\u{3001}', '\u{3003}'),
+    ('\u{3008}', '\u{3011}'),
+    ('\u{3014}', '\u{301f}'),
+    ('\u{3030}', '\u{3030}'),
+    ('\u{303d}', '\u{303d}'),
+    ('\u{30a0}', '\u{30a0}'),
+    ('\u{30fb}', '\u{30fb}'),
+    ('\u{a4fe}', '\u{a4ff}'),
+    ('\u{a60d}', '\u{a60f}'),
+    ('\u{a673}', '\u{a673}'),
+    ('\u{a67e}', '\u{a67e}'),
+    ('\u{a6f2}', '\u{a6f7}'),
+    ('\u{a874}', '\u{a877}'),
+    ('\u{a8ce}', '\u{a8cf}'),
+    ('\u{a8f8}', '\u{a8fa}'),
+    ('\u{a8fc}', '\u{a8fc}'),
+    ('\u{a92e}', '\u{a92f}'),
+    ('\u{a95f}', '\u{a95f}'),
+    ('\u{a9c1}', '\u{a9cd}'),
+    ('\u{a9de}', '\u{a9df}'),
+    ('\u{aa5c}', '\u{aa5f}'),
+    ('\u{aade}', '\u{aadf}'),
+    ('\u{aaf0}', '\u{aaf1}'),
+    ('\u{abeb}', '\u{abeb}'),
+    ('\u{fd3e}', '\u{fd3f}'),
+    ('\u{fe10}', '\u{fe19}'),
+    ('\u{fe30}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe61}'),
+    ('\u{fe63}', '\u{fe63}'),
+    ('\u{fe68}', '\u{fe68}'),
+    ('\u{fe6a}', '\u{fe6b}'),
+    ('\u{ff01}', '\u{ff03}'),
+    ('\u{ff05}', '\u{ff0a}'),
+    ('\u{ff0c}', '\u{ff0f}'),
+    ('\u{ff1a}', '\u{ff1b}'),
+    ('\u{ff1f}', '\u{ff20}'),
+    ('\u{ff3b}', '\u{ff3d}'),
+    ('\u{ff3f}', '\u{ff3f}'),
+    ('\u{ff5b}', '\u{ff5b}'),
+    ('\u{ff5d}', '\u{ff5d}'),
+    ('\u{ff5f}', '\u{ff65}'),
+    ('\u{10100}', '\u{10102}'),
+    ('\u{1039f}', '\u{1039f}'),
+    ('\u{103d0}', '\u{103d0}'),
+    ('\u{1056f}', '\u{1056f}'),
+    ('\u{10857}', '\u{10857}'),
+    ('\u{1091f}', '\u{1091f}'),
+    ('\u{1093f}', '\u{1093f}'),
+    ('\u{10a50}', '\u{10a58}'),
+    ('\u{10a7f}', '\u{10a7f}'),
+    ('\u{10af0}', '\u{10af6}'),
+    ('\u{10b39}', '\u{10b3f}'),
+    ('\u{10b99}', '\u{10b9c}'),
+    ('\u{10ead}', '\u{10ead}'),
+    ('\u{10f55}', '\u{10f59}'),
+    ('\u{10f86}', '\u{10f89}'),
+    ('\u{11047}', '\u{1104d}'),
+    ('\u{110bb}', '\u{110bc}'),
+    ('\u{110be}', '\u{110c1}'),
+    ('\u{11140}', '\u{11143}'),
+    ('\u{11174}', '\u{11175}'),
+    ('\u{111c5}', '\u{111c8}'),
+    ('\u{111cd}', '\u{111cd}'),
+    ('\u{111db}', '\u{111db}'),
+    ('\u{111dd}', '\u{111df}'),
+    ('\u{11238}', '\u{1123d}'),
+    ('\u{112a9}', '\u{112a9}'),
+    ('\u{1144b}', '\u{1144f}'),
+    ('\u{1145a}', '\u{1145b}'),
+    ('\u{1145d}', '\u{1145d}'),
+    ('\u{114c6}', '\u{114c6}'),
+    ('\u{115c1}', '\u{115d7}'),
+    ('\u{11641}', '\u{11643}'),
+    ('\u{11660}', '\u{1166c}'),
+    ('\u{116b9}', '\u{116b9}'),
+    ('\u{1173c}', '\u{1173e}'),
+    ('\u{1183b}', '\u{1183b}'),
+    ('\u{11944}', '\u{11946}'),
+    ('\u{119e2}', '\u{119e2}'),
+    ('\u{11a3f}', '\u{11a46}'),
+    ('\u{11a9a}', '\u{11a9c}'),
+    ('\u{11a9e}', '\u{11aa2}'),
+    ('\u{11c41}', '\u{11c45}'),
+    ('\u{11c70}', '\u{11c71}'),
+    ('\u{11ef7}', '\u{11ef8}'),
+    ('\u{11fff}', '\u{11fff}'),
+    ('\u{12470}', '\u{12474}'),
+    ('\u{12ff1}', '\u{12ff2}'),
+    ('\u{16a6e}', '\u{16a6f}'),
+    ('\u{16af5}', '\u{16af5}'),
+    ('\u{16b37}', '\u{16b3b}'),
+    ('\u{16b44}', '\u{16b44}'),
+    ('\u{16e97}', '\u{16e9a}'),
+    ('\u{16fe2}', '\u{16fe2}'),
+    ('\u{1bc9f}', '\u{1bc9f}'),
+    ('\u{1da87}', '\u{1da8b}'),
+    ('\u{1e95e}', '\u{1e95f}'),
+];
+
+/// Symbols, general categories `Sm`, `Sc`, `Sk` and `So`, which include the emoji.
+pub const SYMBOL: &[(char, char)] = &[
+    ('\u{24}', '\u{24}'),
+    ('\u{2b}', '\u{2b}'),
+    ('\u{3c}', '\u{3e}'),
+    ('\u{5e}', '\u{5e}'),
+    ('\u{60}', '\u{60}'),
+    ('\u{7c}', '\u{7c}'),
+    ('\u{7e}', '\u{7e}'),
+    ('\u{a2}', '\u{a6}'),
+    ('\u{a8}', '\u{a9}'),
+    ('\u{ac}', '\u{ac}'),
+    ('\u{ae}', '\u{b1}'),
+    ('\u{b4}', '\u{b4}'),
+    ('\u{b8}', '\u{b8}'),
+    ('\u{d7}', '\u{d7}'),
+    ('\u{f7}', '\u{f7}'),
+    ('\u{2c2}', '\u{2c5}'),
+    ('\u{2d2}', '\u{2df}'),
+    ('\u{2e5}', '\u{2eb}'),
+    ('\u{2ed}', '\u{2ed}'),
+    ('\u{2ef}', '\u{2ff}'),
+    ('\u{375}', '\u{375}'),
+    ('\u{384}', '\u{385}'),
+    ('\u{3f6}', '\u{3f6}'),
+    ('\u{482}', '\u{482}'),
+    ('\u{58d}', '\u{58f}'),
+    ('\u{606}', '\u{608}'),
+    ('\u{60b}', '\u{60b}'),
+    ('\u{60e}', '\u{60f}'),
+    ('\u{6de}', '\u{6de}'),
+    ('\u{6e9}', '\u{6e9}'),
+    ('\u{6fd}', '\u{6fe}'),
+    ('\u{7f6}', '\u{7f6}'),
+    ('\u{7fe}', '\u{7ff}'),
+    ('\u{888}', '\u{888}'),
+    ('\u{9f2}', '\u{9f3}'),
+    ('\u{9fa}', '\u{9fb}'),
+    ('\u{af1}', '\u{af1}'),
+    ('\u{b70}', '\u{b70}'),
+    ('\u{bf3}', '\u{bfa}'),
+    ('\u{c7f}', '\u{c7f}'),
+    ('\u{d4f}', '\u{d4f}'),
+    ('\u{d79}', '\u{d79}'),
+    ('\u{e3f}', '\u{e3f}'),
+    ('\u{f01}', '\u{f03}'),
+    ('\u{f13}', '\u{f13}'),
+    ('\u{f15}', '\u{f17}'),
+    ('\u{f1a}', '\u{f1f}'),
+    ('\u{f34}', '\u{f34}'),
+    ('\u{f36}', '\u{f36}'),
+    ('\u{f38}', '\u{f38}'),
+    ('\u{fbe}', '\u{fc5}'),
+    ('\u{fc7}', '\u{fcc}'),
+    ('\u{fce}', '\u{fcf}'),
+    ('\u{fd5}', '\u{fd8}'),
+    ('\u{109e}', '\u{109f}'),
+    ('\u{1390}', '\u{1399}'),
+    ('\u{166d}', '\u{166d}'),
+    ('\u{17db}', '\u{17db}'),
+    ('\u{1940}', '\u{1940}'),
+    ('\u{19de}', '\u{19ff}'),
+    ('\u{1b61}', '\u{1b6a}'),
+    ('\u{1b74}', '\u{1b7c}'),
+    ('\u{1fbd}', '\u{1fbd}'),
+    ('\u{1fbf}', '\u{1fc1}'),
+    ('\u{1fcd}', '\u{1fcf}'),
+    ('\u{1fdd}', '\u{1fdf}'),
+    ('\u{1fed}', '\u{1fef}'),
+    ('\u{1ffd}', '\u{1ffe}'),
+    ('\u{2044}', '\u{2044}'),
+    ('\u{2052}', '\u{2052}'),
+    ('\u{207a}', '\u{207c}'),
+    ('\u{208a}', '\u{208c}'),
+    ('\u{20a0}', '\u{20c0}'),
+    ('\u{2100}', '\u{2101}'),
+    ('\u{2103}', '\u{2106}'),
+    ('\u{2108}', '\u{2109}'),
+    ('\u{2114}', '\u{2114}'),
+    ('\u{2116}', '\u{2118}'),
+    ('\u{211e}', '\u{2123}'),
+    ('\u{2125}', '\u{2125}'),
+    ('\u{2127}', '\u{2127}'),
+    ('\u{2129}', '\u{2129}'),
+    ('\u{212e}', '\u{212e}'),
+    ('\u{213a}', '\u{213b}'),
+    ('\u{2140}', '\u{2144}'),
+    ('\u{214a}', '\u{214d}'),
+    ('\u{214f}', '\u{214f}'),
+    ('\u{218a}', '\u{218b}'),
+    ('\u{2190}', '\u{2307}'),
+    ('\u{230c}', '\u{2328}'),
+    ('\u{232b}', '\u{2426}'),
+    ('\u{2440}', '\u{244a}'),
+    ('\u{249c}', '\u{24e9}'),
+    ('\u{2500}', '\u{2767}'),
+    ('\u{2794}', '\u{27c4}'),
+    ('\u{27c7}', '\u{27e5}'),
+    ('\u{27f0}', '\u{2982}'),
+    ('\u{2999}', '\u{29d7}'),
+    ('\u{29dc}', '\u{29fb}'),
+    ('\u{29fe}', '\u{2b73}'),
+    ('\u{2b76}', '\u{2b95}'),
+    ('\u{2b97}', '\u{2bff}'),
+    ('\u{2ce5}', '\u{2cea}'),
+    ('\u{2e50}', '\u{2e51}'),
+    ('\u{2e80}', '\u{2e99}'),
+    ('\u{2e9b}', '\u{2ef3}'),
+    ('\u{2f00}', '\u{2fd5}'),
+    ('\u{2ff0}', '\u{2ffb}'),
+    ('\u{3004}', '\u{3004}'),
+    ('\u{3012}', '\u{3013}'),
+    ('\u{3020}', '\u{3020}'),
+    ('\u{3036}', '\u{3037}'),
+    ('\u{303e}', '\u{303f}'),
+    ('\u{309b}', '\u{309c}'),
+    ('\u{3190}', '\u{3191}'),
+    ('\u{3196}', '\u{319f}'),
+    ('\u{31c0}', '\u{31e3}'),
+    ('\u{3200}', '\u{321e}'),
+    ('\u{322a}', '\u{3247}'),
+    ('\u{3250}', '\u{3250}'),
+    ('\u{3260}', '\u{327f}'),
+    ('\u{328a}', '\u{32b0}'),
+    ('\u{32c0}', '\u{33ff}'),
+    ('\u{4dc0}', '\u{4dff}'),
+    ('\u{a490}', '\u{a4c6}'),
+    ('\u{a700}', '\u{a716}'),
+    ('\u{a720}', '\u{a721}'),
+    ('\u{a789}', '\u{a78a}'),
+    ('\u{a828}', '\u{a82b}'),
+    ('\u{a836}', '\u{a839}'),
+    ('\u{aa77}', '\u{aa79}'),
+    ('\u{ab5b}', '\u{ab5b}'),
+    ('\u{ab6a}', '\u{ab6b}'),
+    ('\u{fb29}', '\u{fb29}'),
+    ('\u{fbb2}', '\u{fbc2}'),
+    ('\u{fd40}', '\u{fd4f}'),
+    ('\u{fdcf}', '\u{fdcf}'),
+    ('\u{fdfc}', '\u{fdff}'),
+    ('\u{fe62}', '\u{fe62}'),
+    ('\u{fe64}', '\u{fe66}'),
+    ('\u{fe69}', '\u{fe69}'),
+    ('\u{ff04}', '\u{ff04}'),
+    ('\u{ff0b}', '\u{ff0b}'),
+    ('\u{ff1c}', '\u{ff1e}'),
+    ('\u{ff3e}', '\u{ff3e}'),
+    ('\u{ff40}', '\u{ff40}'),
+    ('\u{ff5c}', '\u{ff5c}'),
+    ('\u{ff5e}', '\u{ff5e}'),
+    ('\u{ffe0}', '\u{ffe6}'),
+    ('\u{ffe8}', '\u{ffee}'),
+    ('\u{fffc}', '\u{fffd}'),
+    ('\u{10137}', '\u{1013f}'),
+    ('\u{10179}', '\u{10189}'),
+    ('\u{1018c}', '\u{1018e}'),
+    ('\u{10190}', '\u{1019c}'),
+    ('\u{101a0}', '\u{101a0}'),
+    ('\u{101d0}', '\u{101fc}'),
+    ('\u{10877}', '\u{10878}'),
+    ('\u{10ac8}', '\u{10ac8}'),
+    ('\u{1173f}', '\u{1173f}'),
+    ('\u{11fd5}', '\u{11ff1}'),
+    ('\u{16b3c}', '\u{16b3f}'),
+    ('\u{16b45}', '\u{16b45}'),
+    ('\u{1bc9c}', '\u{1bc9c}'),
+    ('\u{1cf50}', '\u{1cfc3}'),
+    ('\u{1d000}', '\u{1d0f5}'),
+    ('\u{1d100}', '\u{1d126}'),
+    ('\u{1d129}', '\u{1d164}'),
+    ('\u{1d16a}', '\u{1d16c}'),
+    ('\u{1d183}', '\u{1d184}'),
+    ('\u{1d18c}', '\u{1d1a9}'),
+    ('\u{1d1ae}', '\u{1d1ea}'),
+    ('\u{1d200}', '\u{1d241}'),
+    ('\u{1d245}', '\u{1d245}'),
+    ('\u{1d300}', '\u{1d356}'),
+    ('\u{1d6c1}', '\u{1d6c1}'),
+    ('\u{1d6db}', '\u{1d6db}'),
+    ('\u{1d6fb}', '\u{1d6fb}'),
+    ('\u{1d715}', '\u{1d715}'),
+    ('\u{1d735}', '\u{1d735}'),
+    ('\u{1d74f}', '\u{1d74f}'),
+    ('\u{1d76f}', '\u{1d76f}'),
+    ('\u{1d789}', '\u{1d789}'),
+    ('\u{1d7a9}', '\u{1d7a9}'),
+    ('\u{1d7c3}', '\u{1d7c3}'),
+    ('\u{1d800}', '\u{1d9ff}'),
+    ('\u{1da37}', '\u{1da3a}'),
+    ('\u{1da6d}', '\u{1da74}'),
+    ('\u{1da76}', '\u{1da83}'),
+    ('\u{1da85}', '\u{1da86}'),
+    ('\u{1e14f}', '\u{1e14f}'),
+    ('\u{1e2ff}', '\u{1e2ff}'),
+    ('\u{1ecac}', '\u{1ecac}'),
+    ('\u{1ecb0}', '\u{1ecb0}'),
+    ('\u{1ed2e}', '\u{1ed2e}'),
+    ('\u{1eef0}', '\u{1eef1}'),
+    ('\u{1f000}', '\u{1f02b}'),
+    ('\u{1f030}', '\u{1f093}'),
+    ('\u{1f0a0}', '\u{1f0ae}'),
+    ('\u{1f0b1}', '\u{1f0bf}'),
+    ('\u{1f0c1}', '\u{1f0cf}'),
+    ('\u{1f0d1}', '\u{1f0f5}'),
+    ('\u{1f10d}', '\u{1f1ad}'),
+    ('\u{1f1e6}', '\u{1f202}'),
+    ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'),
+    ('\u{1f250}', '\u{1f251}'),
+    ('\u{1f260}', '\u{1f265}'),
+    ('\u{1f300}', '\u{1f6d7}'),
+    ('\u{1f6dd}', '\u{1f6ec}'),
+    ('\u{1f6f0}', '\u{1f6fc}'),
+    ('\u{1f700}', '\u{1f773}'),
+    ('\u{1f780}', '\u{1f7d8}'),
+    ('\u{1f7e0}', '\u{1f7eb}'),
+    ('\u{1f7f0}', '\u{1f7f0}'),
+    ('\u{1f800}', '\u{1f80b}'),
+    ('\u{1f810}', '\u{1f847}'),
+    ('\u{1f850}', '\u{1f859}'),
+    ('\u{1f860}', '\u{1f887}'),
+    ('\u{1f890}', '\u{1f8ad}'),
+    ('\u{1f8b0}', '\u{1f8b1}'),
+    ('\u{1f900}', '\u{1fa53}'),
+    ('\u{1fa60}', '\u{1fa6d}'),
+    ('\u{1fa70}', '\u{1fa74}'),
+    ('\u{1fa78}', '\u{1fa7c}'),
+    ('\u{1fa80}', '\u{1fa86}'),
+    ('\u{1fa90}', '\u{1faac}'),
+    ('\u{1fab0}', '\u{1faba}'),
+    ('\u{1fac0}', '\u{1fac5}'),
+    ('\u{1fad0}', '\u{1fad9}'),
+    ('\u{1fae0}', '\u{1fae7}'),
+    ('\u{1faf0}', '\u{1faf6}'),
+    ('\u{1fb00}', '\u{1fb92}'),
+    ('\u{1fb94}', '\u{1fbca}'),
+];
+
+/// Separators, general categories `Zs`, `Zl` and `Zp`.
+pub const SEPARATOR: &[(char, char)] = &[
+    ('\u{20}', '\u{20}'),
+    ('\u{a0}', '\u{a0}'),
+    ('\u{1680}', '\u{1680}'),
+    ('\u{2000}', '\u{200a}'),
+    ('\u{2028}', '\u{2029}'),
+    ('\u{202f}', '\u{202f}'),
+    ('\u{205f}', '\u{205f}'),
+    ('\u{3000}', '\u{3000}'),
+];
+
+/// Control characters, general category `Cc`.
+pub const CONTROL: &[(char, char)] = &[('\u{0}', '\u{1f}'), ('\u{7f}', '\u{9f}')];
+
+/// Format characters, general category `Cf`, such as zero width joiners and bidi controls.
+pub const FORMAT: &[(char, char)] = &[
+    ('\u{ad}', '\u{ad}'),
+    ('\u{600}', '\u{605}'),
+    ('\u{61c}', '\u{61c}'),
+    ('\u{6dd}', '\u{6dd}'),
+    ('\u{70f}', '\u{70f}'),
+    ('\u{890}', '\u{891}'),
+    ('\u{8e2}', '\u{8e2}'),
+    ('\u{180e}', '\u{180e}'),
+    ('\u{200b}', '\u{200f}'),
+    ('\u{202a}', '\u{202e}'),
+    ('\u{2060}', '\u{2064}'),
+    ('\u{2066}', '\u{206f}'),
+    ('\u{feff}', '\u{feff}'),
+    ('\u{fff9}', '\u{fffb}'),
+    ('\u{110bd}', '\u{110bd}'),
+    ('\u{110cd}', '\u{110cd}'),
+    ('\u{13430}', '\u{13438}'),
+    ('\u{1bca0}', '\u{1bca3}'),
+    ('\u{1d173}', '\u{1d17a}'),
+    ('\u{e0001}', '\u{e0001}'),
+    ('\u{e0020}', '\u{e007f}'),
+];
+
+/// Characters with a canonical decomposition, whose NFC and NFD forms differ. Hangul
+/// syllables are left out since they decompose algorithmically.
+pub const DECOMPOSABLE: &[(char, char)] = &[
+    ('\u{c0}', '\u{c5}'),
+    ('\u{c7}', '\u{cf}'),
+    ('\u{d1}', '\u{d6}'),
+    ('\u{d9}', '\u{dd}'),
+    ('\u{e0}', '\u{e5}'),
+    ('\u{e7}', '\u{ef}'),
+    ('\u{f1}', '\u{f6}'),
+    ('\u{f9}', '\u{fd}'),
+    ('\u{ff}', '\u{10f}'),
+    ('\u{112}', '\u{125}'),
+    ('\u{128}', '\u{130}'),
+    ('\u{134}', '\u{137}'),
+    ('\u{139}', '\u{13e}'),
+    ('\u{143}', '\u{148}'),
+    ('\u{14c}', '\u{151}'),
+    ('\u{154}', '\u{165}'),
+    ('\u{168}', '\u{17e}'),
+    ('\u{1a0}', '\u{1a1}'),
+    ('\u{1af}', '\u{1b0}'),
+    ('\u{1cd}', '\u{1dc}'),
+    ('\u{1de}', '\u{1e3}'),
+    ('\u{1e6}', '\u{1f0}'),
+    ('\u{1f4}', '\u{1f5}'),
+    ('\u{1f8}', '\u{21b}'),
+    ('\u{21e}', '\u{21f}'),
+    ('\u{226}', '\u{233}'),
+    ('\u{340}', '\u{341}'),
+    ('\u{343}', '\u{344}'),
+    ('\u{374}', '\u{374}'),
+    ('\u{37e}', '\u{37e}'),
+    ('\u{385}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{390}'),
+    ('\u{3aa}', '\u{3b0}'),
+    ('\u{3ca}', '\u{3ce}'),
+    ('\u{3d3}', '\u{3d4}'),
+    ('\u{400}', '\u{401}'),
+    ('\u{403}', '\u{403}'),
+    ('\u{407}', '\u{407}'),
+    ('\u{40c}', '\u{40e}'),
+    ('\u{419}', '\u{419}'),
+    ('\u{439}', '\u{439}'),
+    ('\u{450}', '\u{451}'),
+    ('\u{453}', '\u{453}'),
+    ('\u{457}', '\u{457}'),
+    ('\u{45c}', '\u{45e}'),
+    ('\u{476}', '\u{477}'),
+    ('\u{4c1}', '\u{4c2}'),
+    ('\u{4d0}', '\u{4d3}'),
+    ('\u{4d6}', '\u{4d7}'),
+    ('\u{4da}', '\u{4df}'),
+    ('\u{4e2}', '\u{4e7}'),
+    ('\u{4ea}', '\u{4f5}'),
+    ('\u{4f8}', '\u{4f9}'),
+    ('\u{622}', '\u{626}'),
+    ('\u{6c0}', '\u{6c0}'),
+    ('\u{6c2}', '\u{6c2}'),
+    ('\u{6d3}', '\u{6d3}'),
+    ('\u{929}', '\u{929}'),
+    ('\u{931}', '\u{931}'),
+    ('\u{934}', '\u{934}'),
+    ('\u{958}', '\u{95f}'),
+    ('\u{9cb}', '\u{9cc}'),
+    ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9df}'),
+    ('\u{a33}', '\u{a33}'),
+    ('\u{a36}', '\u{a36}'),
+    ('\u{a59}', '\u{a5b}'),
+    ('\u{a5e}', '\u{a5e}'),
+    ('\u{b48}', '\u{b48}'),
+    ('\u{b4b}', '\u{b4c}'),
+    ('\u{b5c}', '\u{b5d}'),
+    ('\u{b94}', '\u{b94}'),
+    ('\u{bca}', '\u{bcc}'),
+    ('\u{c48}', '\u{c48}'),
+    ('\u{cc0}', '\u{cc0}'),
+    ('\u{cc7}', '\u{cc8}'),
+    ('\u{cca}', '\u{ccb}'),
+    ('\u{d4a}', '\u{d4c}'),
+    ('\u{dda}', '\u{dda}'),
+    ('\u{ddc}', '\u{dde}'),
+    ('\u{f43}', '\u{f43}'),
+    ('\u{f4d}', '\u{f4d}'),
+    ('\u{f52}', '\u{f52}'),
+    ('\u{f57}', '\u{f57}'),
+    ('\u{f5c}', '\u{f5c}'),
+    ('\u{f69}', '\u{f69}'),
+    ('\u{f73}', '\u{f73}'),
+    ('\u{f75}', '\u{f76}'),
+    ('\u{f78}', '\u{f78}'),
+    ('\u{f81}', '\u{f81}'),
+    ('\u{f93}', '\u{f93}'),
+    ('\u{f9d}', '\u{f9d}'),
+    ('\u{fa2}', '\u{fa2}'),
+    ('\u{fa7}', '\u{fa7}'),
+    ('\u{fac}', '\u{fac}'),
+    ('\u{fb9}', '\u{fb9}'),
+    ('\u{1026}', '\u{1026}'),
+    ('\u{1b06}', '\u{1b06}'),
+    ('\u{1b08}', '\u{1b08}'),
+    ('\u{1b0a}', '\u{1b0a}'),
+    ('\u{1b0c}', '\u{1b0c}'),
+    ('\u{1b0e}', '\u{1b0e}'),
+    ('\u{1b12}', '\u{1b12}'),
+    ('\u{1b3b}', '\u{1b3b}'),
+    ('\u{1b3d}', '\u{1b3d}'),
+    ('\u{1b40}', '\u{1b41}'),
+    ('\u{1b43}', '\u{1b43}'),
+    ('\u{1e00}', '\u{1e99}'),
+    ('\u{1e9b}', '\u{1e9b}'),
+    ('\u{1ea0}', '\u{1ef9}'),
+    ('\u{1f00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fbc}'),
+    ('\u{1fbe}', '\u{1fbe}'),
+    ('\u{1fc1}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fdd}', '\u{1fef}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffd}'),
+    ('\u{2000}', '\u{2001}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{212a}', '\u{212b}'),
+    ('\u{219a}', '\u{219b}'),
+    ('\u{21ae}', '\u{21ae}'),
+    ('\u{21cd}', '\u{21cf}'),
+    ('\u{2204}', '\u{2204}'),
+    ('\u{2209}', '\u{2209}'),
+    ('\u{220c}', '\u{220c}'),
+    ('\u{2224}', '\u{2224}'),
+    ('\u{2226}', '\u{2226}'),
+    ('\u{2241}', '\u{2241}'),
+    ('\u{2244}', '\u{2244}'),
+    ('\u{2247}', '\u{2247}'),
+    ('\u{2249}', '\u{2249}'),
+    ('\u{2260}', '\u{2260}'),
+    ('\u{2262}', '\u{2262}'),
+    ('\u{226d}', '\u{2271}'),
+    ('\u{2274}', '\u{2275}'),
+    ('\u{2278}', '\u{2279}'),
+    ('\u{2280}', '\u{2281}'),
+    ('\u{2284}', '\u{2285}'),
+    ('\u{2288}', '\u{2289}'),
+    ('\u{22ac}', '\u{22af}'),
+    ('\u{22e0}', '\u{22e3}'),
+    ('\u{22ea}', '\u{22ed}'),
+    ('\u{2329}', '\u{232a}'),
+    ('\u{2adc}', '\u{2adc}'),
+    ('\u{304c}', '\u{304c}'),
+    ('\u{304e}', '\u{304e}'),
+    ('\u{3050}', '\u{3050}'),
+    ('\u{3052}', '\u{3052}'),
+    ('\u{3054}', '\u{3054}'),
+    ('\u{3056}', '\u{3056}'),
+    ('\u{3058}', '\u{3058}'),
+    ('\u{305a}', '\u{305a}'),
+    ('\u{305c}', '\u{305c}'),
+    ('\u{305e}', '\u{305e}'),
+    ('\u{3060}', '\u{3060}'),
+    ('\u{3062}', '\u{3062}'),
+    ('\u{3065}', '\u{3065}'),
+    ('\u{3067}', '\u{3067}'),
+    ('\u{3069}', '\u{3069}'),
+    ('\u{3070}', '\u{3071}'),
+    ('\u{3073}', '\u{3074}'),
+    ('\u{3076}', '\u{3077}'),
+    ('\u{3079}', '\u{307a}'),
+    ('\u{307c}', '\u{307d}'),
+    ('\u{3094}', '\u{3094}'),
+    ('\u{309e}', '\u{309e}'),
+    ('\u{30ac}', '\u{30ac}'),
+    ('\u{30ae}', '\u{30ae}'),
+    ('\u{30b0}', '\u{30b0}'),
+    ('\u{30b2}', '\u{30b2}'),
+    ('\u{30b4}', '\u{30b4}'),
+    ('\u{30b6}', '\u{30b6}'),
+    ('\u{30b8}', '\u{30b8}'),
+    ('\u{30ba}', '\u{30ba}'),
+    ('\u{30bc}', '\u{30bc}'),
+    ('\u{30be}', '\u{30be}'),
+    ('\u{30c0}', '\u{30c0}'),
+    ('\u{30c2}', '\u{30c2}'),
+    ('\u{30c5}', '\u{30c5}'),
+    ('\u{30c7}', '\u{30c7}'),
+    ('\u{30c9}', '\u{30c9}'),
+    ('\u{30d0}', '\u{30d1}'),
+    ('\u{30d3}', '\u{30d4}'),
+    ('\u{30d6}', '\u{30d7}'),
+    ('\u{30d9}', '\u{30da}'),
+    ('\u{30dc}', '\u{30dd}'),
+    ('\u{30f4}', '\u{30f4}'),
+    ('\u{30f7}', '\u{30fa}'),
+    ('\u{30fe}', '\u{30fe}'),
+    ('\u{f900}', '\u{fa0d}'),
+    ('\u{fa10}', '\u{fa10}'),
+    ('\u{fa12}', '\u{fa12}'),
+    ('\u{fa15}', '\u{fa1e}'),
+    ('\u{fa20}', '\u{fa20}'),
+    ('\u{fa22}', '\u{fa22}'),
+    ('\u{fa25}', '\u{fa26}'),
+    ('\u{fa2a}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{fb1d}', '\u{fb1d}'),
+    ('\u{fb1f}', '\u{fb1f}'),
+    ('\u{fb2a}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fb4e}'),
+    ('\u{1109a}', '\u{1109a}'),
+    ('\u{1109c}', '\u{1109c}'),
+    ('\u{110ab}', '\u{110ab}'),
+    ('\u{1112e}', '\u{1112f}'),
+    ('\u{1134b}', '\u{1134c}'),
+    ('\u{114bb}', '\u{114bc}'),
+    ('\u{114be}', '\u{114be}'),
+    ('\u{115ba}', '\u{115bb}'),
+    ('\u{11938}', '\u{11938}'),
+    ('\u{1d15e}', '\u{1d164}'),
+    ('\u{1d1bb}', '\u{1d1c0}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+];
+
+/// Characters with a compatibility decomposition, which NFKC and NFKD change, e.g. ligatures,
+/// full width forms and superscripts.
+pub const COMPATIBILITY: &[(char, char)] = &[
+    ('\u{a0}', '\u{a0}'),
+    ('\u{a8}', '\u{a8}'),
+    ('\u{aa}', '\u{aa}'),
+    ('\u{af}', '\u{af}'),
+    ('\u{b2}', '\u{b5}'),
+    ('\u{b8}', '\u{ba}'),
+    ('\u{bc}', '\u{be}'),
+    ('\u{132}', '\u{133}'),
+    ('\u{13f}', '\u{140}'),
+    ('\u{149}', '\u{149}'),
+    ('\u{17f}', '\u{17f}'),
+    ('\u{1c4}', '\u{1cc}'),
+    ('\u{1f1}', '\u{1f3}'),
+    ('\u{2b0}', '\u{2b8}'),
+    ('\u{2d8}', '\u{2dd}'),
+    ('\u{2e0}', '\u{2e4}'),
+    ('\u{37a}', '\u{37a}'),
+    ('\u{384}', '\u{384}'),
+    ('\u{3d0}', '\u{3d2}'),
+    ('\u{3d5}', '\u{3d6}'),
+    ('\u{3f0}', '\u{3f2}'),
+    ('\u{3f4}', '\u{3f5}'),
+    ('\u{3f9}', '\u{3f9}'),
+    ('\u{587}', '\u{587}'),
+    ('\u{675}', '\u{678}'),
+    ('\u{e33}', '\u{e33}'),
+    ('\u{eb3}', '\u{eb3}'),
+    ('\u{edc}', '\u{edd}'),
+    ('\u{f0c}', '\u{f0c}'),
+    ('\u{f77}', '\u{f77}'),
+    ('\u{f79}', '\u{f79}'),
+    ('\u{10fc}', '\u{10fc}'),
+    ('\u{1d2c}', '\u{1d2e}'),
+    ('\u{1d30}', '\u{1d3a}'),
+    ('\u{1d3c}', '\u{1d4d}'),
+    ('\u{1d4f}', '\u{1d6a}'),
+    ('\u{1d78}', '\u{1d78}'),
+    ('\u{1d9b}', '\u{1dbf}'),
+    ('\u{1e9a}', '\u{1e9a}'),
+    ('\u{1fbd}', '\u{1fbd}'),
+    ('\u{1fbf}', '\u{1fc0}'),
+    ('\u{1ffe}', '\u{1ffe}'),
+    ('\u{2002}', '\u{200a}'),
+    ('\u{2011}', '\u{2011}'),
+    ('\u{2017}', '\u{2017}'),
+    ('\u{2024}', '\u{2026}'),
+    ('\u{202f}', '\u{202f}'),
+    ('\u{2033}', '\u{2034}'),
+    ('\u{2036}', '\u{2037}'),
+    ('\u{203c}', '\u{203c}'),
+    ('\u{203e}', '\u{203e}'),
+    ('\u{2047}', '\u{2049}'),
+    ('\u{2057}', '\u{2057}'),
+    ('\u{205f}', '\u{205f}'),
+    ('\u{2070}', '\u{2071}'),
+    ('\u{2074}', '\u{208e}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{20a8}', '\u{20a8}'),
+    ('\u{2100}', '\u{2103}'),
+    ('\u{2105}', '\u{2107}'),
+    ('\u{2109}', '\u{2113}'),
+    ('\u{2115}', '\u{2116}'),
+    ('\u{2119}', '\u{211d}'),
+    ('\u{2120}', '\u{2122}'),
+    ('\u{2124}', '\u{2124}'),
+    ('\u{2128}', '\u{2128}'),
+    ('\u{212c}', '\u{212d}'),
+    ('\u{212f}', '\u{2131}'),
+    ('\u{2133}', '\u{2139}'),
+    ('\u{213b}', '\u{2140}'),
+    ('\u{2145}', '\u{2149}'),
+    ('\u{2150}', '\u{217f}'),
+    ('\u{2189}', '\u{2189}'),
+    ('\u{222c}', '\u{222d}'),
+    ('\u{222f}', '\u{2230}'),
+    ('\u{2460}', '\u{24ea}'),
+    ('\u{2a0c}', '\u{2a0c}'),
+    ('\u{2a74}', '\u{2a76}'),
+    ('\u{2c7c}', '\u{2c7d}'),
+    ('\u{2d6f}', '\u{2d6f}'),
+    ('\u{2e9f}', '\u{2e9f}'),
+    ('\u{2ef3}', '\u{2ef3}'),
+    ('\u{2f00}', '\u{2fd5}'),
+    ('\u{3000}', '\u{3000}'),
+    ('\u{3036}', '\u{3036}'),
+    ('\u{3038}', '\u{303a}'),
+    ('\u{309b}', '\u{309c}'),
+    ('\u{309f}', '\u{309f}'),
+    ('\u{30ff}', '\u{30ff}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{3192}', '\u{319f}'),
+    ('\u{3200}', '\u{321e}'),
+    ('\u{3220}', '\u{3247}'),
+    ('\u{3250}', '\u{327e}'),
+    ('\u{3280}', '\u{33ff}'),
+    ('\u{a69c}', '\u{a69d}'),
+    ('\u{a770}', '\u{a770}'),
+    ('\u{a7f2}', '\u{a7f4}'),
+    ('\u{a7f8}', '\u{a7f9}'),
+    ('\u{ab5c}', '\u{ab5f}'),
+    ('\u{ab69}', '\u{ab69}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{fb13}', '\u{fb17}'),
+    ('\u{fb20}', '\u{fb29}'),
+    ('\u{fb4f}', '\u{fbb1}'),
+    ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd50}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdf0}', '\u{fdfc}'),
+    ('\u{fe10}', '\u{fe19}'),
+    ('\u{fe30}', '\u{fe44}'),
+    ('\u{fe47}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe66}'),
+    ('\u{fe68}', '\u{fe6b}'),
+    ('\u{fe70}', '\u{fe72}'),
+    ('\u{fe74}', '\u{fe74}'),
+    ('\u{fe76}', '\u{fefc}'),
+    ('\u{ff01}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+    ('\u{ffe0}', '\u{ffe6}'),
+    ('\u{ffe8}', '\u{ffee}'),
+    ('\u{10781}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d7cb}'),
+    ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1f100}', '\u{1f10a}'),
+    ('\u{1f110}', '\u{1f12e}'),
+    ('\u{1f130}', '\u{1f14f}'),
+    ('\u{1f16a}', '\u{1f16c}'),
+    ('\u{1f190}', '\u{1f190}'),
+    ('\u{1f200}', '\u{1f202}'),
+    ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'),
+    ('\u{1f250}', '\u{1f251}'),
+    ('\u{1fbf0}', '\u{1fbf9}'),
+];
+
+/// A selection of scripts, from the Unicode `Script` property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Tamil,
+    Thai,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+    Ethiopic,
+    Common,
+    Inherited,
+}
+
+impl Script {
+    /// The characters of this script.
+    pub fn ranges(self) -> &'static [(char, char)] {
+        match self {
+            Script::Latin => LATIN,
+            Script::Greek => GREEK,
+            Script::Cyrillic => CYRILLIC,
+            Script::Armenian => ARMENIAN,
+            Script::Hebrew => HEBREW,
+            Script::Arabic => ARABIC,
+            Script::Devanagari => DEVANAGARI,
+            Script::Bengali => BENGALI,
+            Script::Tamil => TAMIL,
+            Script::Thai => THAI,
+            Script::Georgian => GEORGIAN,
+            Script::Hangul => HANGUL,
+            Script::Hiragana => HIRAGANA,
+            Script::Katakana => KATAKANA,
+            Script::Han => HAN,
+            Script::Ethiopic => ETHIOPIC,
+            Script::Common => COMMON,
+            Script::Inherited => INHERITED,
+        }
+    }
+}
+
+const LATIN: &[(char, char)] = &[
+    ('\u{41}', '\u{5a}'),
+    ('\u{61}', '\u{7a}'),
+    ('\u{aa}', '\u{aa}'),
+    ('\u{ba}', '\u{ba}'),
+    ('\u{c0}', '\u{d6}'),
+    ('\u{d8}', '\u{f6}'),
+    ('\u{f8}', '\u{2b8}'),
+    ('\u{2e0}', '\u{2e4}'),
+    ('\u{1d00}', '\u{1d25}'),
+    ('\u{1d2c}', '\u{1d5c}'),
+    ('\u{1d62}', '\u{1d65}'),
+    ('\u{1d6b}', '\u{1d77}'),
+    ('\u{1d79}', '\u{1dbe}'),
+    ('\u{1e00}', '\u{1eff}'),
+    ('\u{2071}', '\u{2071}'),
+    ('\u{207f}', '\u{207f}'),
+    ('\u{2090}', '\u{209c}'),
+    ('\u{212a}', '\u{212b}'),
+    ('\u{2132}', '\u{2132}'),
+    ('\u{214e}', '\u{214e}'),
+    ('\u{2160}', '\u{2188}'),
+    ('\u{2c60}', '\u{2c7f}'),
+    ('\u{a722}', '\u{a787}'),
+    ('\u{a78b}', '\u{a7ca}'),
+    ('\u{a7d0}', '\u{a7d1}'),
+    ('\u{a7d3}', '\u{a7d3}'),
+    ('\u{a7d5}', '\u{a7d9}'),
+    ('\u{a7f2}', '\u{a7ff}'),
+    ('\u{ab30}', '\u{ab5a}'),
+    ('\u{ab5c}', '\u{ab64}'),
+    ('\u{ab66}', '\u{ab69}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    ('\u{ff41}', '\u{ff5a}'),
+    ('\u{10780}', '\u{10785}'),
+    ('\u{10787}', '\u{107b0}'),
+    ('\u{107b2}', '\u{107ba}'),
+    ('\u{1df00}', '\u{1df1e}'),
+];
+
+const GREEK: &[(char, char)] = &[
+    ('\u{370}', '\u{373}'),
+    ('\u{375}', '\u{377}'),
+    ('\u{37a}', '\u{37d}'),
+    ('\u{37f}', '\u{37f}'),
+    ('\u{384}', '\u{384}'),
+    ('\u{386}', '\u{386}'),
+    ('\u{388}', '\u{38a}'),
+    ('\u{38c}', '\u{38c}'),
+    ('\u{38e}', '\u{3a1}'),
+    ('\u{3a3}', '\u{3e1}'),
+    ('\u{3f0}', '\u{3ff}'),
+    ('\u{1d26}', '\u{1d2a}'),
+    ('\u{1d5d}', '\u{1d61}'),
+    ('\u{1d66}', '\u{1d6a}'),
+    ('\u{1dbf}', '\u{1dbf}'),
+    ('\u{1f00}', '\u{1f15}'),
+    ('\u{1f18}', '\u{1f1d}'),
+    ('\u{1f20}', '\u{1f45}'),
+    ('\u{1f48}', '\u{1f4d}'),
+    ('\u{1f50}', '\u{1f57}'),
+    ('\u{1f59}', '\u{1f59}'),
+    ('\u{1f5b}', '\u{1f5b}'),
+    ('\u{1f5d}', '\u{1f5d}'),
+    ('\u{1f5f}', '\u{1f7d}'),
+    ('\u{1f80}', '\u{1fb4}'),
+    ('\u{1fb6}', '\u{1fc4}'),
+    ('\u{1fc6}', '\u{1fd3}'),
+    ('\u{1fd6}', '\u{1fdb}'),
+    ('\u{1fdd}', '\u{1fef}'),
+    ('\u{1ff2}', '\u{1ff4}'),
+    ('\u{1ff6}', '\u{1ffe}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{ab65}', '\u{ab65}'),
+    ('\u{10140}', '\u{1018e}'),
+    ('\u{101a0}', '\u{101a0}'),
+    ('\u{1d200}', '\u{1d245}'),
+];
+
+const CYRILLIC: &[(char, char)] = &[
+    ('\u{400}', '\u{484}'),
+    ('\u{487}', '\u{52f}'),
+    ('\u{1c80}', '\u{1c88}'),
+    ('\u{1d2b}', '\u{1d2b}'),
+    ('\u{1d78}', '\u{1d78}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{a640}', '\u{a69f}'),
+    ('\u{fe2e}', '\u{fe2f}'),
+];
+
+const ARMENIAN: &[(char, char)] = &[
+    ('\u{531}', '\u{556}'),
+    ('\u{559}', '\u{58a}'),
+    ('\u{58d}', '\u{58f}'),
+    ('\u{fb13}', '\u{fb17}'),
+];
+
+const HEBREW: &[(char, char)] = &[
+    ('\u{591}', '\u{5c7}'),
+    ('\u{5d0}', '\u{5ea}'),
+    ('\u{5ef}', '\u{5f4}'),
+    ('\u{fb1d}', '\u{fb36}'),
+    ('\u{fb38}', '\u{fb3c}'),
+    ('\u{fb3e}', '\u{fb3e}'),
+    ('\u{fb40}', '\u{fb41}'),
+    ('\u{fb43}', '\u{fb44}'),
+    ('\u{fb46}', '\u{fb4f}'),
+];
+
+const ARABIC: &[(char, char)] = &[
+    ('\u{600}', '\u{604}'),
+    ('\u{606}', '\u{60b}'),
+    ('\u{60d}', '\u{61a}'),
+    ('\u{61c}', '\u{61e}'),
+    ('\u{620}', '\u{63f}'),
+    ('\u{641}', '\u{64a}'),
+    ('\u{656}', '\u{66f}'),
+    ('\u{671}', '\u{6dc}'),
+    ('\u{6de}', '\u{6ff}'),
+    ('\u{750}', '\u{77f}'),
+    ('\u{870}', '\u{88e}'),
+    ('\u{890}', '\u{891}'),
+    ('\u{898}', '\u{8e1}'),
+    ('\u{8e3}', '\u{8ff}'),
+    ('\u{fb50}', '\u{fbc2}'),
+    ('\u{fbd3}', '\u{fd3d}'),
+    ('\u{fd40}', '\u{fd8f}'),
+    ('\u{fd92}', '\u{fdc7}'),
+    ('\u{fdcf}', '\u{fdcf}'),
+    ('\u{fdf0}', '\u{fdff}'),
+    ('\u{fe70}', '\u{fe74}'),
+    ('\u{fe76}', '\u{fefc}'),
+    ('\u{10e60}', '\u{10e7e}'),
+    ('\u{1ee00}', '\u{1ee03}'),
+    ('\u{1ee05}', '\u{1ee1f}'),
+    ('\u{1ee21}', '\u{1ee22}'),
+    ('\u{1ee24}', '\u{1ee24}'),
+    ('\u{1ee27}', '\u{1ee27}'),
+    ('\u{1ee29}', '\u{1ee32}'),
+    ('\u{1ee34}', '\u{1ee37}'),
+    ('\u{1ee39}', '\u{1ee39}'),
+    ('\u{1ee3b}', '\u{1ee3b}'),
+    ('\u{1ee42}', '\u{1ee42}'),
+    ('\u{1ee47}', '\u{1ee47}'),
+    ('\u{1ee49}', '\u{1ee49}'),
+    ('\u{1ee4b}', '\u{1ee4b}'),
+    ('\u{1ee4d}', '\u{1ee4f}'),
+    ('\u{1ee51}', '\u{1ee52}'),
+    ('\u{1ee54}', '\u{1ee54}'),
+    ('\u{1ee57}', '\u{1ee57}'),
+    ('\u{1ee59}', '\u{1ee59}'),
+    ('\u{1ee5b}', '\u{1ee5b}'),
+    ('\u{1ee5d}', '\u{1ee5d}'),
+    ('\u{1ee5f}', '\u{1ee5f}'),
+    ('\u{1ee61}', '\u{1ee62}'),
+    ('\u{1ee64}', '\u{1ee64}'),
+    ('\u{1ee67}', '\u{1ee6a}'),
+    ('\u{1ee6c}', '\u{1ee72}'),
+    ('\u{1ee74}', '\u{1ee77}'),
+    ('\u{1ee79}', '\u{1ee7c}'),
+    ('\u{1ee7e}', '\u{1ee7e}'),
+    ('\u{1ee80}', '\u{1ee89}'),
+    ('\u{1ee8b}', '\u{1ee9b}'),
+    ('\u{1eea1}', '\u{1eea3}'),
+    ('\u{1eea5}', '\u{1eea9}'),
+    ('\u{1eeab}', '\u{1eebb}'),
+    ('\u{1eef0}', '\u{1eef1}'),
+];
+
+const DEVANAGARI: &[(char, char)] = &[
+    ('\u{900}', '\u{950}'),
+    ('\u{955}', '\u{963}'),
+    ('\u{966}', '\u{97f}'),
+    ('\u{a8e0}', '\u{a8ff}'),
+];
+
+const BENGALI: &[(char, char)] = &[
+    ('\u{980}', '\u{983}'),
+    ('\u{985}', '\u{98c}'),
+    ('\u{98f}', '\u{990}'),
+    ('\u{993}', '\u{9a8}'),
+    ('\u{9aa}', '\u{9b0}'),
+    ('\u{9b2}', '\u{9b2}'),
+    ('\u{9b6}', '\u{9b9}'),
+    ('\u{9bc}', '\u{9c4}'),
+    ('\u{9c7}', '\u{9c8}'),
+    ('\u{9cb}', '\u{9ce}'),
+    ('\u{9d7}', '\u{9d7}'),
+    ('\u{9dc}', '\u{9dd}'),
+    ('\u{9df}', '\u{9e3}'),
+    ('\u{9e6}', '\u{9fe}'),
+];
+
+const TAMIL: &[(char, char)] = &[
+    ('\u{b82}', '\u{b83}'),
+    ('\u{b85}', '\u{b8a}'),
+    ('\u{b8e}', '\u{b90}'),
+    ('\u{b92}', '\u{b95}'),
+    ('\u{b99}', '\u{b9a}'),
+    ('\u{b9c}', '\u{b9c}'),
+    ('\u{b9e}', '\u{b9f}'),
+    ('\u{ba3}', '\u{ba4}'),
+    ('\u{ba8}', '\u{baa}'),
+    ('\u{bae}', '\u{bb9}'),
+    ('\u{bbe}', '\u{bc2}'),
+    ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'),
+    ('\u{bd0}', '\u{bd0}'),
+    ('\u{bd7}', '\u{bd7}'),
+    ('\u{be6}', '\u{bfa}'),
+    ('\u{11fc0}', '\u{11ff1}'),
+    ('\u{11fff}', '\u{11fff}'),
+];
+
+const THAI: &[(char, char)] = &[('\u{e01}', '\u{e3a}'), ('\u{e40}', '\u{e5b}')];
+
+const GEORGIAN: &[(char, char)] = &[
+    ('\u{10a0}', '\u{10c5}'),
+    ('\u{10c7}', '\u{10c7}'),
+    ('\u{10cd}', '\u{10cd}'),
+    ('\u{10d0}', '\u{10fa}'),
+    ('\u{10fc}', '\u{10ff}'),
+    ('\u{1c90}', '\u{1cba}'),
+    ('\u{1cbd}', '\u{1cbf}'),
+    ('\u{2d00}', '\u{2d25}'),
+    ('\u{2d27}', '\u{2d27}'),
+    ('\u{2d2d}', '\u{2d2d}'),
+];
+
+const HANGUL: &[(char, char)] = &[
+    ('\u{1100}', '\u{11ff}'),
+    ('\u{302e}', '\u{302f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{3200}', '\u{321e}'),
+    ('\u{3260}', '\u{327e}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{d7b0}', '\u{d7c6}'),
+    ('\u{d7cb}', '\u{d7fb}'),
+    ('\u{ffa0}', '\u{ffbe}'),
+    ('\u{ffc2}', '\u{ffc7}'),
+    ('\u{ffca}', '\u{ffcf}'),
+    ('\u{ffd2}', '\u{ffd7}'),
+    ('\u{ffda}', '\u{ffdc}'),
+];
+
+const HIRAGANA: &[(char, char)] = &[
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309d}', '\u{309f}'),
+    ('\u{1b001}', '\u{1b11f}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1f200}', '\u{1f200}'),
+];
+
+const KATAKANA: &[(char, char)] = &[
+    ('\u{30a1}', '\u{30fa}'),
+    ('\u{30fd}', '\u{30ff}'),
+    ('\u{31f0}', '\u{31ff}'),
+    ('\u{32d0}', '\u{32fe}'),
+    ('\u{3300}', '\u{3357}'),
+    ('\u{ff66}', '\u{ff6f}'),
+    ('\u{ff71}', '\u{ff9d}'),
+    ('\u{1aff0}', '\u{1aff3}'),
+    ('\u{1aff5}', '\u{1affb}'),
+    ('\u{1affd}', '\u{1affe}'),
+    ('\u{1b000}', '\u{1b000}'),
+    ('\u{1b120}', '\u{1b122}'),
+    ('\u{1b164}', '\u{1b167}'),
+];
+
+const HAN: &[(char, char)] = &[
+    ('\u{2e80}', '\u{2e99}'),
+    ('\u{2e9b}', '\u{2ef3}'),
+    ('\u{2f00}', '\u{2fd5}'),
+    ('\u{3005}', '\u{3005}'),
+    ('\u{3007}', '\u{3007}'),
+    ('\u{3021}', '\u{3029}'),
+    ('\u{3038}', '\u{303b}'),
+    ('\u{3400}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{9fff}'),
+    ('\u{f900}', '\u{fa6d}'),
+    ('\u{fa70}', '\u{fad9}'),
+    ('\u{16fe2}', '\u{16fe3}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{20000}', '\u{2a6df}'),
+    ('\u{2a700}', '\u{2b738}'),
+    ('\u{2b740}', '\u{2b81d}'),
+    ('\u{2b820}', '\u{2cea1}'),
+    ('\u{2ceb0}', '\u{2ebe0}'),
+    ('\u{2f800}', '\u{2fa1d}'),
+    ('\u{30000}', '\u{3134a}'),
+];
+
+const ETHIOPIC: &[(char, char)] = &[
+    ('\u{1200}', '\u{1248}'),
+    ('\u{124a}', '\u{124d}'),
+    ('\u{1250}', '\u{1256}'),
+    ('\u{1258}', '\u{1258}'),
+    ('\u{125a}', '\u{125d}'),
+    ('\u{1260}', '\u{1288}'),
+    ('\u{128a}', '\u{128d}'),
+    ('\u{1290}', '\u{12b0}'),
+    ('\u{12b2}', '\u{12b5}'),
+    ('\u{12b8}', '\u{12be}'),
+    ('\u{12c0}', '\u{12c0}'),
+    ('\u{12c2}', '\u{12c5}'),
+    ('\u{12c8}', '\u{12d6}'),
+    ('\u{12d8}', '\u{1310}'),
+    ('\u{1312}', '\u{1315}'),
+    ('\u{1318}', '\u{135a}'),
+    ('\u{135d}', '\u{137c}'),
+    ('\u{1380}', '\u{1399}'),
+    ('\u{2d80}', '\u{2d96}'),
+    ('\u{2da0}', '\u{2da6}'),
+    ('\u{2da8}', '\u{2dae}'),
+    ('\u{2db0}', '\u{2db6}'),
+    ('\u{2db8}', '\u{2dbe}'),
+    ('\u{2dc0}', '\u{2dc6}'),
+    ('\u{2dc8}', '\u{2dce}'),
+    ('\u{2dd0}', '\u{2dd6}'),
+    ('\u{2dd8}', '\u{2dde}'),
+    ('\u{ab01}', '\u{ab06}'),
+    ('\u{ab09}', '\u{ab0e}'),
+    ('\u{ab11}', '\u{ab16}'),
+    ('\u{ab20}', '\u{ab26}'),
+    ('\u{ab28}', '\u{ab2e}'),
+    ('\u{1e7e0}', '\u{1e7e6}'),
+    ('\u{1e7e8}', '\u{1e7eb}'),
+    ('\u{1e7ed}', '\u{1e7ee}'),
+    ('\u{1e7f0}', '\u{1e7fe}'),
+];
+
+const COMMON: &[(char, char)] = &[
+    ('\u{0}', '\u{40}'),
+    ('\u{5b}', '\u{60}'),
+    ('\u{7b}', '\u{a9}'),
+    ('\u{ab}', '\u{b9}'),
+    ('\u{bb}', '\u{bf}'),
+    ('\u{d7}', '\u{d7}'),
+    ('\u{f7}', '\u{f7}'),
+    ('\u{2b9}', '\u{2df}'),
+    ('\u{2e5}', '\u{2e9}'),
+    ('\u{2ec}', '\u{2ff}'),
+    ('\u{374}', '\u{374}'),
+    ('\u{37e}', '\u{37e}'),
+    ('\u{385}', '\u{385}'),
+    ('\u{387}', '\u{387}'),
+    ('\u{605}', '\u{605}'),
+    ('\u{60c}', '\u{60c}'),
+    ('\u{61b}', '\u{61b}'),
+    ('\u{61f}', '\u{61f}'),
+    ('\u{640}', '\u{640}'),
+    ('\u{6dd}', '\u{6dd}'),
+    ('\u{8e2}', '\u{8e2}'),
+    ('\u{964}', '\u{965}'),
+    ('\u{e3f}', '\u{e3f}'),
+    ('\u{fd5}', '\u{fd8}'),
+    ('\u{10fb}', '\u{10fb}'),
+    ('\u{16eb}', '\u{16ed}'),
+    ('\u{1735}', '\u{1736}'),
+    ('\u{1802}', '\u{1803}'),
+    ('\u{1805}', '\u{1805}'),
+    ('\u{1cd3}', '\u{1cd3}'),
+    ('\u{1ce1}', '\u{1ce1}'),
+    ('\u{1ce9}', '\u{1cec}'),
+    ('\u{1cee}', '\u{1cf3}'),
+    ('\u{1cf5}', '\u{1cf7}'),
+    ('\u{1cfa}', '\u{1cfa}'),
+    ('\u{2000}', '\u{200b}'),
+    ('\u{200e}', '\u{2064}'),
+    ('\u{2066}', '\u{2070}'),
+    ('\u{2074}', '\u{207e}'),
+    ('\u{2080}', '\u{208e}'),
+    ('\u{20a0}', '\u{20c0}'),
+    ('\u{2100}', '\u{2125}'),
+    ('\u{2127}', '\u{2129}'),
+    ('\u{212c}', '\u{2131}'),
+    ('\u{2133}', '\u{214d}'),
+    ('\u{214f}', '\u{215f}'),
+    ('\u{2189}', '\u{218b}'),
+    ('\u{2190}', '\u{2426}'),
+    ('\u{2440}', '\u{244a}'),
+    ('\u{2460}', '\u{27ff}'),
+    ('\u{2900}', '\u{2b73}'),
+    ('\u{2b76}', '\u{2b95}'),
+    ('\u{2b97}', '\u{2bff}'),
+    ('\u{2e00}', '\u{2e5d}'),
+    ('\u{2ff0}', '\u{2ffb}'),
+    ('\u{3000}', '\u{3004}'),
+    ('\u{3006}', '\u{3006}'),
+    ('\u{3008}', '\u{3020}'),
+    ('\u{3030}', '\u{3037}'),
+    ('\u{303c}', '\u{303f}'),
+    ('\u{309b}', '\u{309c}'),
+    ('\u{30a0}', '\u{30a0}'),
+    ('\u{30fb}', '\u{30fc}'),
+    ('\u{3190}', '\u{319f}'),
+    ('\u{31c0}', '\u{31e3}'),
+    ('\u{3220}', '\u{325f}'),
+    ('\u{327f}', '\u{32cf}'),
+    ('\u{32ff}', '\u{32ff}'),
+    ('\u{3358}', '\u{33ff}'),
+    ('\u{4dc0}', '\u{4dff}'),
+    ('\u{a700}', '\u{a721}'),
+    ('\u{a788}', '\u{a78a}'),
+    ('\u{a830}', '\u{a839}'),
+    ('\u{a92e}', '\u{a92e}'),
+    ('\u{a9cf}', '\u{a9cf}'),
+    ('\u{ab5b}', '\u{ab5b}'),
+    ('\u{ab6a}', '\u{ab6b}'),
+    ('\u{fd3e}', '\u{fd3f}'),
+    ('\u{fe10}', '\u{fe19}'),
+    ('\u{fe30}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe66}'),
+    ('\u{fe68}', '\u{fe6b}'),
+    ('\u{feff}', '\u{feff}'),
+    ('\u{ff01}', '\u{ff20}'),
+    ('\u{ff3b}', '\u{ff40}'),
+    ('\u{ff5b}', '\u{ff65}'),
+    ('\u{ff70}', '\u{ff70}'),
+    ('\u{ff9e}', '\u{ff9f}'),
+    ('\u{ffe0}', '\u{ffe6}'),
+    ('\u{ffe8}', '\u{ffee}'),
+    ('\u{fff9}', '\u{fffd}'),
+    ('\u{10100}', '\u{10102}'),
+    ('\u{10107}', '\u{10133}'),
+    ('\u{10137}', '\u{1013f}'),
+    ('\u{10190}', '\u{1019c}'),
+    ('\u{101d0}', '\u{101fc}'),
+    ('\u{102e1}', '\u{102fb}'),
+    ('\u{1bca0}', '\u{1bca3}'),
+    ('\u{1cf50}', '\u{1cfc3}'),
+    ('\u{1d000}', '\u{1d0f5}'),
+    ('\u{1d100}', '\u{1d126}'),
+    ('\u{1d129}', '\u{1d166}'),
+    ('\u{1d16a}', '\u{1d17a}'),
+    ('\u{1d183}', '\u{1d184}'),
+    ('\u{1d18c}', '\u{1d1a9}'),
+    ('\u{1d1ae}', '\u{1d1ea}'),
+    ('\u{1d2e0}', '\u{1d2f3}'),
+    ('\u{1d300}', '\u{1d356}'),
+    ('\u{1d360}', '\u{1d378}'),
+    ('\u{1d400}', '\u{1d454}'),
+    ('\u{1d456}', '\u{1d49c}'),
+    ('\u{1d49e}', '\u{1d49f}'),
+    ('\u{1d4a2}', '\u{1d4a2}'),
+    ('\u{1d4a5}', '\u{1d4a6}'),
+    ('\u{1d4a9}', '\u{1d4ac}'),
+    ('\u{1d4ae}', '\u{1d4b9}'),
+    ('\u{1d4bb}', '\u{1d4bb}'),
+    ('\u{1d4bd}', '\u{1d4c3}'),
+    ('\u{1d4c5}', '\u{1d505}'),
+    ('\u{1d507}', '\u{1d50a}'),
+    ('\u{1d50d}', '\u{1d514}'),
+    ('\u{1d516}', '\u{1d51c}'),
+    ('\u{1d51e}', '\u{1d539}'),
+    ('\u{1d53b}', '\u{1d53e}'),
+    ('\u{1d540}', '\u{1d544}'),
+    ('\u{1d546}', '\u{1d546}'),
+    ('\u{1d54a}', '\u{1d550}'),
+    ('\u{1d552}', '\u{1d6a5}'),
+    ('\u{1d6a8}', '\u{1d7cb}'),
+    ('\u{1d7ce}', '\u{1d7ff}'),
+    ('\u{1ec71}', '\u{1ecb4}'),
+    ('\u{1ed01}', '\u{1ed3d}'),
+    ('\u{1f000}', '\u{1f02b}'),
+    ('\u{1f030}', '\u{1f093}'),
+    ('\u{1f0a0}', '\u{1f0ae}'),
+    ('\u{1f0b1}', '\u{1f0bf}'),
+    ('\u{1f0c1}', '\u{1f0cf}'),
+    ('\u{1f0d1}', '\u{1f0f5}'),
+    ('\u{1f100}', '\u{1f1ad}'),
+    ('\u{1f1e6}', '\u{1f1ff}'),
+    ('\u{1f201}', '\u{1f202}'),
+    ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'),
+    ('\u{1f250}', '\u{1f251}'),
+    ('\u{1f260}', '\u{1f265}'),
+    ('\u{1f300}', '\u{1f6d7}'),
+    ('\u{1f6dd}', '\u{1f6ec}'),
+    ('\u{1f6f0}', '\u{1f6fc}'),
+    ('\u{1f700}', '\u{1f773}'),
+    ('\u{1f780}', '\u{1f7d8}'),
+    ('\u{1f7e0}', '\u{1f7eb}'),
+    ('\u{1f7f0}', '\u{1f7f0}'),
+    ('\u{1f800}', '\u{1f80b}'),
+    ('\u{1f810}', '\u{1f847}'),
+    ('\u{1f850}', '\u{1f859}'),
+    ('\u{1f860}', '\u{1f887}'),
+    ('\u{1f890}', '\u{1f8ad}'),
+    ('\u{1f8b0}', '\u{1f8b1}'),
+    ('\u{1f900}', '\u{1fa53}'),
+    ('\u{1fa60}', '\u{1fa6d}'),
+    ('\u{1fa70}', '\u{1fa74}'),
+    ('\u{1fa78}', '\u{1fa7c}'),
+    ('\u{1fa80}', '\u{1fa86}'),
+    ('\u{1fa90}', '\u{1faac}'),
+    ('\u{1fab0}', '\u{1faba}'),
+    ('\u{1fac0}', '\u{1fac5}'),
+    ('\u{1fad0}', '\u{1fad9}'),
+    ('\u{1fae0}', '\u{1fae7}'),
+    ('\u{1faf0}', '\u{1faf6}'),
+    ('\u{1fb00}', '\u{1fb92}'),
+    ('\u{1fb94}', '\u{1fbca}'),
+    ('\u{1fbf0}', '\u{1fbf9}'),
+    ('\u{e0001}', '\u{e0001}'),
+    ('\u{e0020}', '\u{e007f}'),
+];
+
+const INHERITED: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'),
+    ('\u{485}', '\u{486}'),
+    ('\u{64b}', '\u{655}'),
+    ('\u{670}', '\u{670}'),
+    ('\u{951}', '\u{954}'),
+    ('\u{1ab0}', '\u{1ace}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce0}'),
+    ('\u{1ce2}', '\u{1ce8}'),
+    ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf8}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'),
+    ('\u{200c}', '\u{200d}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{302a}', '\u{302d}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2d}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{1133b}', '\u{1133b}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d167}', '\u{1d169}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+/// Every block as `(name, first, last)`, except the surrogate blocks which hold no `char`s.
+pub const BLOCKS: &[(&str, char, char)] = &[
+    ("Basic Latin", '\u{0}', '\u{7f}'),
+    ("Latin-1 Supplement", '\u{80}', '\u{ff}'),
+    ("Latin Extended-A", '\u{100}', '\u{17f}'),
+    ("Latin Extended-B", '\u{180}', '\u{24f}'),
+    ("IPA Extensions", '\u{250}', '\u{2af}'),
+    ("Spacing Modifier Letters", '\u{2b0}', '\u{2ff}'),
+    ("Combining Diacritical Marks", '\u{300}', '\u{36f}'),
+    ("Greek and Coptic", '\u{370}', '\u{3ff}'),
+    ("Cyrillic", '\u{400}', '\u{4ff}'),
+    ("Cyrillic Supplement", '\u{500}', '\u{52f}'),
+    ("Armenian", '\u{530}', '\u{58f}'),
+    ("Hebrew", '\u{590}', '\u{5ff}'),
+    ("Arabic", '\u{600}', '\u{6ff}'),
+    ("Syriac", '\u{700}', '\u{74f}'),
+    ("Arabic Supplement", '\u{750}', '\u{77f}'),
+    ("Thaana", '\u{780}', '\u{7bf}'),
+    ("NKo", '\u{7c0}', '\u{7ff}'),
+    ("Samaritan", '\u{800}', '\u{83f}'),
+    ("Mandaic", '\u{840}', '\u{85f}'),
+    ("Syriac Supplement", '\u{860}', '\u{86f}'),
+    ("Arabic Extended-B", '\u{870}', '\u{89f}'),
+    ("Arabic Extended-A", '\u{8a0}', '\u{8ff}'),
+    ("Devanagari", '\u{900}', '\u{97f}'),
+    ("Bengali", '\u{980}', '\u{9ff}'),
+    ("Gurmukhi", '\u{a00}', '\u{a7f}'),
+    ("Gujarati", '\u{a80}', '\u{aff}'),
+    ("Oriya", '\u{b00}', '\u{b7f}'),
+    ("Tamil", '\u{b80}', '\u{bff}'),
+    ("Telugu", '\u{c00}', '\u{c7f}'),
+    ("Kannada", '\u{c80}', '\u{cff}'),
+    ("Malayalam", '\u{d00}', '\u{d7f}'),
+    ("Sinhala", '\u{d80}', '\u{dff}'),
+    ("Thai", '\u{e00}', '\u{e7f}'),
+    ("Lao", '\u{e80}', '\u{eff}'),
+    ("Tibetan", '\u{f00}', '\u{fff}'),
+    ("Myanmar", '\u{1000}', '\u{109f}'),
+    ("Georgian", '\u{10a0}', '\u{10ff}'),
+    ("Hangul Jamo", '\u{1100}', '\u{11ff}'),
+    ("Ethiopic", '\u{1200}', '\u{137f}'),
+    ("Ethiopic Supplement", '\u{1380}', '\u{139f}'),
+    ("Cherokee", '\u{13a0}', '\u{13ff}'),
+    (
+        "Unified Canadian Aboriginal Syllabics",
+        '\u{1400}',
+        '\u{167f}',
+    ),
+    ("Ogham", '\u{1680}', '\u{169f}'),
+    ("Runic", '\u{16a0}', '\u{16ff}'),
+    ("Tagalog", '\u{1700}', '\u{171f}'),
+    ("Hanunoo", '\u{1720}', '\u{173f}'),
+    ("Buhid", '\u{1740}', '\u{175f}'),
+    ("Tagbanwa", '\u{1760}', '\u{177f}'),
+    ("Khmer", '\u{1780}', '\u{17ff}'),
+    ("Mongolian", '\u{1800}', '\u{18af}'),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended",
+        '\u{18b0}',
+        '\u{18ff}',
+    ),
+    ("Limbu", '\u{1900}', '\u{194f}'),
+    ("Tai Le", '\u{1950}', '\u{197f}'),
+    ("New Tai Lue", '\u{1980}', '\u{19df}'),
+    ("Khmer Symbols", '\u{19e0}', '\u{19ff}'),
+    ("Buginese", '\u{1a00}', '\u{1a1f}'),
+    ("Tai Tham", '\u{1a20}', '\u{1aaf}'),
+    (
+        "Combining Diacritical Marks Extended",
+        '\u{1ab0}',
+        '\u{1aff}',
+    ),
+    ("Balinese", '\u{1b00}', '\u{1b7f}'),
+    ("Sundanese", '\u{1b80}', '\u{1bbf}'),
+    ("Batak", '\u{1bc0}', '\u{1bff}'),
+    ("Lepcha", '\u{1c00}', '\u{1c4f}'),
+    ("Ol Chiki", '\u{1c50}', '\u{1c7f}'),
+    ("Cyrillic Extended-C", '\u{1c80}', '\u{1c8f}'),
+    ("Georgian Extended", '\u{1c90}', '\u{1cbf}'),
+    ("Sundanese Supplement", '\u{1cc0}', '\u{1ccf}'),
+    ("Vedic Extensions", '\u{1cd0}', '\u{1cff}'),
+    ("Phonetic Extensions", '\u{1d00}', '\u{1d7f}'),
+    ("Phonetic Extensions Supplement", '\u{1d80}', '\u{1dbf}'),
+    (
+        "Combining Diacritical Marks Supplement",
+        '\u{1dc0}',
+        '\u{1dff}',
+    ),
+    ("Latin Extended Additional", '\u{1e00}', '\u{1eff}'),
+    ("Greek Extended", '\u{1f00}', '\u{1fff}'),
+    ("General Punctuation", '\u{2000}', '\u{206f}'),
+    ("Superscripts and Subscripts", '\u{2070}', '\u{209f}'),
+    ("Currency Symbols", '\u{20a0}', '\u{20cf}'),
+    (
+        "Combining Diacritical Marks for Symbols",
+        '\u{20d0}',
+        '\u{20ff}',
+    ),
+    ("Letterlike Symbols", '\u{2100}', '\u{214f}'),
+    ("Number Forms", '\u{2150}', '\u{218f}'),
+    ("Arrows", '\u{2190}', '\u{21ff}'),
+    ("Mathematical Operators", '\u{2200}', '\u{22ff}'),
+    ("Miscellaneous Technical", '\u{2300}', '\u{23ff}'),
+    ("Control Pictures", '\u{2400}', '\u{243f}'),
+    ("Optical Character Recognition", '\u{2440}', '\u{245f}'),
+    ("Enclosed Alphanumerics", '\u{2460}', '\u{24ff}'),
+    ("Box Drawing", '\u{2500}', '\u{257f}'),
+    ("Block Elements", '\u{2580}', '\u{259f}'),
+    ("Geometric Shapes", '\u{25a0}', '\u{25ff}'),
+    ("Miscellaneous Symbols", '\u{2600}', '\u{26ff}'),
+    ("Dingbats", '\u{2700}', '\u{27bf}'),
+    (
+        "Miscellaneous Mathematical Symbols-A",
+        '\u{27c0}',
+        '\u{27ef}',
+    ),
+    ("Supplemental Arrows-A", '\u{27f0}', '\u{27ff}'),
+    ("Braille Patterns", '\u{2800}', '\u{28ff}'),
+    ("Supplemental Arrows-B", '\u{2900}', '\u{297f}'),
+    (
+        "Miscellaneous Mathematical Symbols-B",
+        '\u{2980}',
+        '\u{29ff}',
+    ),
+    (
+        "Supplemental Mathematical Operators",
+        '\u{2a00}',
+        '\u{2aff}',
+    ),
+    ("Miscellaneous Symbols and Arrows", '\u{2b00}', '\u{2bff}'),
+    ("Glagolitic", '\u{2c00}', '\u{2c5f}'),
+    ("Latin Extended-C", '\u{2c60}', '\u{2c7f}'),
+    ("Coptic", '\u{2c80}', '\u{2cff}'),
+    ("Georgian Supplement", '\u{2d00}', '\u{2d2f}'),
+    ("Tifinagh", '\u{2d30}', '\u{2d7f}'),
+    ("Ethiopic Extended", '\u{2d80}', '\u{2ddf}'),
+    ("Cyrillic Extended-A", '\u{2de0}', '\u{2dff}'),
+    ("Supplemental Punctuation", '\u{2e00}', '\u{2e7f}'),
+    ("CJK Radicals Supplement", '\u{2e80}', '\u{2eff}'),
+    ("Kangxi Radicals", '\u{2f00}', '\u{2fdf}'),
+    ("Ideographic Description Characters", '\u{2ff0}', '\u{2fff}'),
+    ("CJK Symbols and Punctuation", '\u{3000}', '\u{303f}'),
+    ("Hiragana", '\u{3040}', '\u{309f}'),
+    ("Katakana", '\u{30a0}', '\u{30ff}'),
+    ("Bopomofo", '\u{3100}', '\u{312f}'),
+    ("Hangul Compatibility Jamo", '\u{3130}', '\u{318f}'),
+    ("Kanbun", '\u{3190}', '\u{319f}'),
+    ("Bopomofo Extended", '\u{31a0}', '\u{31bf}'),
+    ("CJK Strokes", '\u{31c0}', '\u{31ef}'),
+    ("Katakana Phonetic Extensions", '\u{31f0}', '\u{31ff}'),
+    ("Enclosed CJK Letters and Months", '\u{3200}', '\u{32ff}'),
+    ("CJK Compatibility", '\u{3300}', '\u{33ff}'),
+    ("CJK Unified Ideographs Extension A", '\u{3400}', '\u{4dbf}'),
+    ("Yijing Hexagram Symbols", '\u{4dc0}', '\u{4dff}'),
+    ("CJK Unified Ideographs", '\u{4e00}', '\u{9fff}'),
+    ("Yi Syllables", '\u{a000}', '\u{a48f}'),
+    ("Yi Radicals", '\u{a490}', '\u{a4cf}'),
+    ("Lisu", '\u{a4d0}', '\u{a4ff}'),
+    ("Vai", '\u{a500}', '\u{a63f}'),
+    ("Cyrillic Extended-B", '\u{a640}', '\u{a69f}'),
+    ("Bamum", '\u{a6a0}', '\u{a6ff}'),
+    ("Modifier Tone Letters", '\u{a700}', '\u{a71f}'),
+    ("Latin Extended-D", '\u{a720}', '\u{a7ff}'),
+    ("Syloti Nagri", '\u{a800}', '\u{a82f}'),
+    ("Common Indic Number Forms", '\u{a830}', '\u{a83f}'),
+    ("Phags-pa", '\u{a840}', '\u{a87f}'),
+    ("Saurashtra", '\u{a880}', '\u{a8df}'),
+    ("Devanagari Extended", '\u{a8e0}', '\u{a8ff}'),
+    ("Kayah Li", '\u{a900}', '\u{a92f}'),
+    ("Rejang", '\u{a930}', '\u{a95f}'),
+    ("Hangul Jamo Extended-A", '\u{a960}', '\u{a97f}'),
+    ("Javanese", '\u{a980}', '\u{a9df}'),
+    ("Myanmar Extended-B", '\u{a9e0}', '\u{a9ff}'),
+    ("Cham", '\u{aa00}', '\u{aa5f}'),
+    ("Myanmar Extended-A", '\u{aa60}', '\u{aa7f}'),
+    ("Tai Viet", '\u{aa80}', '\u{aadf}'),
+    ("Meetei Mayek Extensions", '\u{aae0}', '\u{aaff}'),
+    ("Ethiopic Extended-A", '\u{ab00}', '\u{ab2f}'),
+    ("Latin Extended-E", '\u{ab30}', '\u{ab6f}'),
+    ("Cherokee Supplement", '\u{ab70}', '\u{abbf}'),
+    ("Meetei Mayek", '\u{abc0}', '\u{abff}'),
+    ("Hangul Syllables", '\u{ac00}', '\u{d7af}'),
+    ("Hangul Jamo Extended-B", '\u{d7b0}', '\u{d7ff}'),
+    ("Private Use Area", '\u{e000}', '\u{f8ff}'),
+    ("CJK Compatibility Ideographs", '\u{f900}', '\u{faff}'),
+    ("Alphabetic Presentation Forms", '\u{fb00}', '\u{fb4f}'),
+    ("Arabic Presentation Forms-A", '\u{fb50}', '\u{fdff}'),
+    ("Variation Selectors", '\u{fe00}', '\u{fe0f}'),
+    ("Vertical Forms", '\u{fe10}', '\u{fe1f}'),
+    ("Combining Half Marks", '\u{fe20}', '\u{fe2f}'),
+    ("CJK Compatibility Forms", '\u{fe30}', '\u{fe4f}'),
+    ("Small Form Variants", '\u{fe50}', '\u{fe6f}'),
+    ("Arabic Presentation Forms-B", '\u{fe70}', '\u{feff}'),
+    ("Halfwidth and Fullwidth Forms", '\u{ff00}', '\u{ffef}'),
+    ("Specials", '\u{fff0}', '\u{ffff}'),
+    ("Linear B Syllabary", '\u{10000}', '\u{1007f}'),
+    ("Linear B Ideograms", '\u{10080}', '\u{100ff}'),
+    ("Aegean Numbers", '\u{10100}', '\u{1013f}'),
+    ("Ancient Greek Numbers", '\u{10140}', '\u{1018f}'),
+    ("Ancient Symbols", '\u{10190}', '\u{101cf}'),
+    ("Phaistos Disc", '\u{101d0}', '\u{101ff}'),
+    ("Lycian", '\u{10280}', '\u{1029f}'),
+    ("Carian", '\u{102a0}', '\u{102df}'),
+    ("Coptic Epact Numbers", '\u{102e0}', '\u{102ff}'),
+    ("Old Italic", '\u{10300}', '\u{1032f}'),
+    ("Gothic", '\u{10330}', '\u{1034f}'),
+    ("Old Permic", '\u{10350}', '\u{1037f}'),
+    ("Ugaritic", '\u{10380}', '\u{1039f}'),
+    ("Old Persian", '\u{103a0}', '\u{103df}'),
+    ("Deseret", '\u{10400}', '\u{1044f}'),
+    ("Shavian", '\u{10450}', '\u{1047f}'),
+    ("Osmanya", '\u{10480}', '\u{104af}'),
+    ("Osage", '\u{104b0}', '\u{104ff}'),
+    ("Elbasan", '\u{10500}', '\u{1052f}'),
+    ("Caucasian Albanian", '\u{10530}', '\u{1056f}'),
+    ("Vithkuqi", '\u{10570}', '\u{105bf}'),
+    ("Linear A", '\u{10600}', '\u{1077f}'),
+    ("Latin Extended-F", '\u{10780}', '\u{107bf}'),
+    ("Cypriot Syllabary", '\u{10800}', '\u{1083f}'),
+    ("Imperial Aramaic", '\u{10840}', '\u{1085f}'),
+    ("Palmyrene", '\u{10860}', '\u{1087f}'),
+    ("Nabataean", '\u{10880}', '\u{108af}'),
+    ("Hatran", '\u{108e0}', '\u{108ff}'),
+    ("Phoenician", '\u{10900}', '\u{1091f}'),
+    ("Lydian", '\u{10920}', '\u{1093f}'),
+    ("Meroitic Hieroglyphs", '\u{10980}', '\u{1099f}'),
+    ("Meroitic Cursive", '\u{109a0}', '\u{109ff}'),
+    ("Kharoshthi", '\u{10a00}', '\u{10a5f}'),
+    ("Old South Arabian", '\u{10a60}', '\u{10a7f}'),
+    ("Old North Arabian", '\u{10a80}', '\u{10a9f}'),
+    ("Manichaean", '\u{10ac0}', '\u{10aff}'),
+    ("Avestan", '\u{10b00}', '\u{10b3f}'),
+    ("Inscriptional Parthian", '\u{10b40}', '\u{10b5f}'),
+    ("Inscriptional Pahlavi", '\u{10b60}', '\u{10b7f}'),
+    ("Psalter Pahlavi", '\u{10b80}', '\u{10baf}'),
+    ("Old Turkic", '\u{10c00}', '\u{10c4f}'),
+    ("Old Hungarian", '\u{10c80}', '\u{10cff}'),
+    ("Hanifi Rohingya", '\u{10d00}', '\u{10d3f}'),
+    ("Rumi Numeral Symbols", '\u{10e60}', '\u{10e7f}'),
+    ("Yezidi", '\u{10e80}', '\u{10ebf}'),
+    ("Old Sogdian", '\u{10f00}', '\u{10f2f}'),
+    ("Sogdian", '\u{10f30}', '\u{10f6f}'),
+    ("Old Uyghur", '\u{10f70}', '\u{10faf}'),
+    ("Chorasmian", '\u{10fb0}', '\u{10fdf}'),
+    ("Elymaic", '\u{10fe0}', '\u{10fff}'),
+    ("Brahmi", '\u{11000}', '\u{1107f}'),
+    ("Kaithi", '\u{11080}', '\u{110cf}'),
+    ("Sora Sompeng", '\u{110d0}', '\u{110ff}'),
+    ("Chakma", '\u{11100}', '\u{1114f}'),
+    ("Mahajani", '\u{11150}', '\u{1117f}'),
+    ("Sharada", '\u{11180}', '\u{111df}'),
+    ("Sinhala Archaic Numbers", '\u{111e0}', '\u{111ff}'),
+    ("Khojki", '\u{11200}', '\u{1124f}'),
+    ("Multani", '\u{11280}', '\u{112af}'),
+    ("Khudawadi", '\u{112b0}', '\u{112ff}'),
+    ("Grantha", '\u{11300}', '\u{1137f}'),
+    ("Newa", '\u{11400}', '\u{1147f}'),
+    ("Tirhuta", '\u{11480}', '\u{114df}'),
+    ("Siddham", '\u{11580}', '\u{115ff}'),
+    ("Modi", '\u{11600}', '\u{1165f}'),
+    ("Mongolian Supplement", '\u{11660}', '\u{1167f}'),
+    ("Takri", '\u{11680}', '\u{116cf}'),
+    ("Ahom", '\u{11700}', '\u{1174f}'),
+    ("Dogra", '\u{11800}', '\u{1184f}'),
+    ("Warang Citi", '\u{118a0}', '\u{118ff}'),
+    ("Dives Akuru", '\u{11900}', '\u{1195f}'),
+    ("Nandinagari", '\u{119a0}', '\u{119ff}'),
+    ("Zanabazar Square", '\u{11a00}', '\u{11a4f}'),
+    ("Soyombo", '\u{11a50}', '\u{11aaf}'),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended-A",
+        '\u{11ab0}',
+        '\u{11abf}',
+    ),
+    ("Pau Cin Hau", '\u{11ac0}', '\u{11aff}'),
+    ("Bhaiksuki", '\u{11c00}', '\u{11c6f}'),
+    ("Marchen", '\u{11c70}', '\u{11cbf}'),
+    ("Masaram Gondi", '\u{11d00}', '\u{11d5f}'),
+    ("Gunjala Gondi", '\u{11d60}', '\u{11daf}'),
+    ("Makasar", '\u{11ee0}', '\u{11eff}'),
+    ("Lisu Supplement", '\u{11fb0}', '\u{11fbf}'),
+    ("Tamil Supplement", '\u{11fc0}', '\u{11fff}'),
+    ("Cuneiform", '\u{12000}', '\u{123ff}'),
+    (
+        "Cuneiform Numbers and Punctuation",
+        '\u{12400}',
+        '\u{1247f}',
+    ),
+    ("Early Dynastic Cuneiform", '\u{12480}', '\u{1254f}'),
+    ("Cypro-Minoan", '\u{12f90}', '\u{12fff}'),
+    ("Egyptian Hieroglyphs", '\u{13000}', '\u{1342f}'),
+    (
+        "Egyptian Hieroglyph Format Controls",
+        '\u{13430}',
+        '\u{1343f}',
+    ),
+    ("Anatolian Hieroglyphs", '\u{14400}', '\u{1467f}'),
+    ("Bamum Supplement", '\u{16800}', '\u{16a3f}'),
+    ("Mro", '\u{16a40}', '\u{16a6f}'),
+    ("Tangsa", '\u{16a70}', '\u{16acf}'),
+    ("Bassa Vah", '\u{16ad0}', '\u{16aff}'),
+    ("Pahawh Hmong", '\u{16b00}', '\u{16b8f}'),
+    ("Medefaidrin", '\u{16e40}', '\u{16e9f}'),
+    ("Miao", '\u{16f00}', '\u{16f9f}'),
+    (
+        "Ideographic Symbols and Punctuation",
+        '\u{16fe0}',
+        '\u{16fff}',
+    ),
+    ("Tangut", '\u{17000}', '\u{187ff}'),
+    ("Tangut Components", '\u{18800}', '\u{18aff}'),
+    ("Khitan Small Script", '\u{18b00}', '\u{18cff}'),
+    ("Tangut Supplement", '\u{18d00}', '\u{18d7f}'),
+    ("Kana Extended-B", '\u{1aff0}', '\u{1afff}'),
+    ("Kana Supplement", '\u{1b000}', '\u{1b0ff}'),
+    ("Kana Extended-A", '\u{1b100}', '\u{1b12f}'),
+    ("Small Kana Extension", '\u{1b130}', '\u{1b16f}'),
+    ("Nushu", '\u{1b170}', '\u{1b2ff}'),
+    ("Duployan", '\u{1bc00}', '\u{1bc9f}'),
+    ("Shorthand Format Controls", '\u{1bca0}', '\u{1bcaf}'),
+    ("Znamenny Musical Notation", '\u{1cf00}', '\u{1cfcf}'),
+    ("Byzantine Musical Symbols", '\u{1d000}', '\u{1d0ff}'),
+    ("Musical Symbols", '\u{1d100}', '\u{1d1ff}'),
+    ("Ancient Greek Musical Notation", '\u{1d200}', '\u{1d24f}'),
+    ("Mayan Numerals", '\u{1d2e0}', '\u{1d2ff}'),
+    ("Tai Xuan Jing Symbols", '\u{1d300}', '\u{1d35f}'),
+    ("Counting Rod Numerals", '\u{1d360}', '\u{1d37f}'),
+    (
+        "Mathematical Alphanumeric Symbols",
+        '\u{1d400}',
+        '\u{1d7ff}',
+    ),
+    ("Sutton SignWriting", '\u{1d800}', '\u{1daaf}'),
+    ("Latin Extended-G", '\u{1df00}', '\u{1dfff}'),
+    ("Glagolitic Supplement", '\u{1e000}', '\u{1e02f}'),
+    ("Nyiakeng Puachue Hmong", '\u{1e100}', '\u{1e14f}'),
+    ("Toto", '\u{1e290}', '\u{1e2bf}'),
+    ("Wancho", '\u{1e2c0}', '\u{1e2ff}'),
+    ("Ethiopic Extended-B", '\u{1e7e0}', '\u{1e7ff}'),
+    ("Mende Kikakui", '\u{1e800}', '\u{1e8df}'),
+    ("Adlam", '\u{1e900}', '\u{1e95f}'),
+    ("Indic Siyaq Numbers", '\u{1ec70}', '\u{1ecbf}'),
+    ("Ottoman Siyaq Numbers", '\u{1ed00}', '\u{1ed4f}'),
+    (
+        "Arabic Mathematical Alphabetic Symbols",
+        '\u{1ee00}',
+        '\u{1eeff}',
+    ),
+    ("Mahjong Tiles", '\u{1f000}', '\u{1f02f}'),
+    ("Domino Tiles", '\u{1f030}', '\u{1f09f}'),
+    ("Playing Cards", '\u{1f0a0}', '\u{1f0ff}'),
+    ("Enclosed Alphanumeric Supplement", '\u{1f100}', '\u{1f1ff}'),
+    ("Enclosed Ideographic Supplement", '\u{1f200}', '\u{1f2ff}'),
+    (
+        "Miscellaneous Symbols and Pictographs",
+        '\u{1f300}',
+        '\u{1f5ff}',
+    ),
+    ("Emoticons", '\u{1f600}', '\u{1f64f}'),
+    ("Ornamental Dingbats", '\u{1f650}', '\u{1f67f}'),
+    ("Transport and Map Symbols", '\u{1f680}', '\u{1f6ff}'),
+    ("Alchemical Symbols", '\u{1f700}', '\u{1f77f}'),
+    ("Geometric Shapes Extended", '\u{1f780}', '\u{1f7ff}'),
+    ("Supplemental Arrows-C", '\u{1f800}', '\u{1f8ff}'),
+    (
+        "Supplemental Symbols and Pictographs",
+        '\u{1f900}',
+        '\u{1f9ff}',
+    ),
+    ("Chess Symbols", '\u{1fa00}', '\u{1fa6f}'),
+    (
+        "Symbols and Pictographs Extended-A",
+        '\u{1fa70}',
+        '\u{1faff}',
+    ),
+    ("Symbols for Legacy Computing", '\u{1fb00}', '\u{1fbff}'),
+    (
+        "CJK Unified Ideographs Extension B",
+        '\u{20000}',
+        '\u{2a6df}',
+    ),
+    (
+        "CJK Unified Ideographs Extension C",
+        '\u{2a700}',
+        '\u{2b73f}',
+    ),
+    (
+        "CJK Unified Ideographs Extension D",
+        '\u{2b740}',
+        '\u{2b81f}',
+    ),
+    (
+        "CJK Unified Ideographs Extension E",
+        '\u{2b820}',
+        '\u{2ceaf}',
+    ),
+    (
+        "CJK Unified Ideographs Extension F",
+        '\u{2ceb0}',
+        '\u{2ebef}',
+    ),
+    (
+        "CJK Compatibility Ideographs Supplement",
+        '\u{2f800}',
+        '\u{2fa1f}',
+    ),
+    (
+        "CJK Unified Ideographs Extension G",
+        '\u{30000}',
+        '\u{3134f}',
+    ),
+    ("Tags", '\u{e0000}', '\u{e007f}'),
+    ("Variation Selectors Supplement", '\u{e0100}', '\u{e01ef}'),
+    ("Supplementary Private Use Area-A", '\u{f0000}', '\u{fffff}'),
+    (
+        "Supplementary Private Use Area-B",
+        '\u{100000}',
+        '\u{10ffff}',
+    ),
+];
+
+// End of the tables generated by scripts/unicode_tables.py.
+
+/// Characters that tend to break text handling, grouped by the trouble they cause.
+pub const INTERESTING: &[(char, char)] = &[
+    // Line breaks, including CR and LF which form a single grapheme together.
+    ('\u{9}', '\u{d}'),
+    ('\u{85}', '\u{85}'),
+    ('\u{2028}', '\u{2029}'),
+    // Whitespace other than the space.
+    ('\u{a0}', '\u{a0}'),
+    ('\u{1680}', '\u{1680}'),
+    ('\u{2000}', '\u{200a}'),
+    ('\u{202f}', '\u{202f}'),
+    ('\u{205f}', '\u{205f}'),
+    ('\u{3000}', '\u{3000}'),
+    // Invisible characters: soft hyphen, zero width space and joiners, word joiner, byte order
+    // mark.
+    ('\u{ad}', '\u{ad}'),
+    ('\u{200b}', '\u{200d}'),
+    ('\u{2060}', '\u{2064}'),
+    ('\u{feff}', '\u{feff}'),
+    // Bidirectional marks, embeddings, overrides and isolates.
+    ('\u{200e}', '\u{200f}'),
+    ('\u{202a}', '\u{202e}'),
+    ('\u{2066}', '\u{2069}'),
+    // Right-to-left letters and digits.
+    ('\u{5d0}', '\u{5ea}'),
+    ('\u{627}', '\u{64a}'),
+    ('\u{660}', '\u{669}'),
+    // Combining marks and variation selectors, which extend the grapheme before them.
+    ('\u{300}', '\u{36f}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{e0100}', '\u{e01ef}'),
+    // Normalization: precomposed letters, singleton decompositions (Ohm, Kelvin and Angstrom
+    // signs), ligatures, superscripts and full width forms.
+    ('\u{c0}', '\u{c5}'),
+    ('\u{e9}', '\u{e9}'),
+    ('\u{1e9b}', '\u{1e9b}'),
+    ('\u{2126}', '\u{2126}'),
+    ('\u{212a}', '\u{212b}'),
+    ('\u{fb00}', '\u{fb06}'),
+    ('\u{b2}', '\u{b3}'),
+    ('\u{ff21}', '\u{ff3a}'),
+    // Case mapping: sharp s, dotted and dotless i, long s, final sigma and titlecase digraphs.
+    ('\u{df}', '\u{df}'),
+    ('\u{1e9e}', '\u{1e9e}'),
+    ('\u{130}', '\u{131}'),
+    ('\u{17f}', '\u{17f}'),
+    ('\u{3a3}', '\u{3a3}'),
+    ('\u{3c2}', '\u{3c3}'),
+    ('\u{1c4}', '\u{1cc}'),
+    // Hangul jamo, which combine into syllables, and the first syllable.
+    ('\u{1100}', '\u{11ff}'),
+    ('\u{ac00}', '\u{ac00}'),
+    // Emoji: regional indicators (flags are pairs of them), skin tone modifiers, faces, the
+    // heart and the tags used in subdivision flags.
+    ('\u{1f1e6}', '\u{1f1ff}'),
+    ('\u{1f3fb}', '\u{1f3ff}'),
+    ('\u{1f600}', '\u{1f64f}'),
+    ('\u{2764}', '\u{2764}'),
+    ('\u{e0020}', '\u{e007f}'),
+    // Noncharacters, the replacement character and private use.
+    ('\u{fdd0}', '\u{fdef}'),
+    ('\u{fffd}', '\u{ffff}'),
+    ('\u{10fffe}', '\u{10ffff}'),
+    ('\u{e000}', '\u{e000}'),
+    ('\u{f8ff}', '\u{f8ff}'),
+    // The edges of the UTF-8 and UTF-16 encodings.
+    ('\u{0}', '\u{0}'),
+    ('\u{7f}', '\u{80}'),
+    ('\u{7ff}', '\u{800}'),
+    ('\u{d7ff}', '\u{d7ff}'),
+    ('\u{10000}', '\u{10000}'),
+];
+
+/// Look up a block by name. As recommended by UAX #44, case, whitespace, hyphens and underscores
+/// are ignored, so `"latin extended a"` finds `Latin Extended-A`.
+pub fn block(name: &str) -> Option<(char, char)> {
+    fn loose(name: &str) -> String {
+        name.chars()
+            .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+    let name = loose(name);
+    BLOCKS
+        .iter()
+        .find(|&&(block, _, _)| loose(block) == name)
+        .map(|&(_, first, last)| (first, last))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn is_sorted_and_disjoint(table: &[(char, char)]) -> bool {
+        table.iter().all(|&(lo, hi)| lo <= hi) && table.windows(2).all(|w| w[0].1 < w[1].0)
+    }
+
+    #[test]
+    fn tables_are_sorted_and_disjoint() {
+        let tables = [
+            LETTER,
+            MARK,
+            NUMBER,
+            PUNCTUATION,
+            SYMBOL,
+            SEPARATOR,
+            CONTROL,
+            FORMAT,
+            DECOMPOSABLE,
+            COMPATIBILITY,
+            Script::Latin.ranges(),
+            Script::Han.ranges(),
+            Script::Common.ranges(),
+        ];
+        assert!(tables.iter().all(|t| is_sorted_and_disjoint(t)));
+        assert!(INTERESTING.iter().all(|&(lo, hi)| lo <= hi));
+        assert!(BLOCKS.windows(2).all(|w| w[0].2 < w[1].1));
+    }
+
+    #[test]
+    fn tables_agree_with_the_standard_library() {
+        let chars = |table: &[(char, char)]| -> Vec<char> {
+            table.iter().flat_map(|&(lo, hi)| lo..=hi).collect()
+        };
+        assert!(chars(LETTER).iter().all(|c| c.is_alphabetic()));
+        assert!(chars(NUMBER).iter().all(|c| c.is_numeric()));
+        assert!(chars(SEPARATOR).iter().all(|c| c.is_whitespace()));
+        assert_eq!(
+            chars(CONTROL),
+            chars(&[('\0', '\u{1f}'), ('\u{7f}', '\u{9f}')])
+        );
+        assert!(chars(Script::Greek.ranges()).contains(&'λ'));
+        assert!(chars(Script::Hebrew.ranges()).contains(&'א'));
+        assert!(chars(MARK).contains(&'\u{301}'));
+    }
+
+    #[test]
+    fn blocks_are_found_by_loose_name() {
+        assert_eq!(block("Basic Latin"), Some(('\0', '\u{7f}')));
+        assert_eq!(block("latin extended_a"), Some(('\u{100}', '\u{17f}')));
+        assert_eq!(
+            block("CJK Unified Ideographs"),
+            Some(('\u{4e00}', '\u{9fff}'))
+        );
+        assert_eq!(block("High Surrogates"), None);
+        assert_eq!(block("Klingon"), None);
+    }
+}